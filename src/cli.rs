@@ -0,0 +1,723 @@
+use std::{
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    client::{self, Client, Curl, Fetched, Verdict},
+    common::grid::{Format, Style},
+    history::{Guess, History},
+    puzzle::Description,
+    runner::{self, Tool, Year},
+    scaffold,
+};
+
+/// Represents a command with the year of the puzzles it applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    /// Represents the year of the puzzles, which defaults to the latest registered year.
+    pub year: u32,
+    /// Represents the command to execute.
+    pub command: Command,
+}
+
+impl Args {
+    /// Parse the command line arguments, excluding the program name.
+    ///
+    /// The year is given by `--year <year>`, anywhere among the arguments.
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let mut year = None;
+        let mut rest = Vec::new();
+
+        // Take the year out of the arguments, leaving the rest to the command.
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => year = Some(parse_year(args.next())?),
+                _ => rest.push(arg),
+            }
+        }
+
+        Ok(Args {
+            year: year.unwrap_or_else(runner::latest_year),
+            command: Command::parse(rest)?,
+        })
+    }
+
+    /// Execute the command for the year, printing its output to stdout.
+    pub fn execute(self) -> Result<(), String> {
+        self.command.execute(self.year)
+    }
+}
+
+/// Represents a command parsed from the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Run every registered solution, or only those of a single day.
+    Run {
+        /// Represents the day to run, if restricted to a single one.
+        day: Option<u32>,
+        /// Represents whether to print each executed instruction before running.
+        trace: bool,
+        /// Represents whether to print the highlighted puzzle grid before running.
+        visualize: bool,
+    },
+    /// Download the puzzle input for a single day, unless it is already cached.
+    Fetch {
+        /// Represents the day to download.
+        day: u32,
+    },
+    /// Submit the answer of a single part, unless the guess history rules it out.
+    Submit {
+        /// Represents the day to submit.
+        day: u32,
+        /// Represents the part to submit.
+        part: u32,
+    },
+    /// Generate and register the module of a new day.
+    New {
+        /// Represents the day to generate.
+        day: u32,
+        /// Represents a saved puzzle description whose first code block is the sample.
+        html: Option<String>,
+    },
+    /// Extract the samples and expected answers of an existing day from its puzzle description.
+    Samples {
+        /// Represents the day whose samples are written.
+        day: u32,
+        /// Represents the saved puzzle description.
+        html: String,
+    },
+    /// Print an analysis of the puzzle input for a single day.
+    Analyze {
+        /// Represents the day to analyze.
+        day: u32,
+        /// Represents whether to print JSON instead of a table.
+        json: bool,
+        /// Represents an input file to analyze instead of the puzzle input.
+        input: Option<String>,
+    },
+    /// Write an image of the puzzle input for a single day, highlighting one part's matches.
+    Export {
+        /// Represents the day to export.
+        day: u32,
+        /// Represents the part whose matches are highlighted.
+        part: u32,
+        /// Represents the path of the image, whose extension selects its format.
+        output: String,
+        /// Represents an input file to export instead of the puzzle input.
+        input: Option<String>,
+    },
+    /// Print a diagram of the rule graph for a single day, or of the rules of one update.
+    Graph {
+        /// Represents the day to draw.
+        day: u32,
+        /// Represents the 1-based index of the update to draw, if restricted to a single one.
+        update: Option<usize>,
+        /// Represents whether to print a Mermaid flowchart instead of a DOT document.
+        mermaid: bool,
+        /// Represents an input file to draw instead of the puzzle input.
+        input: Option<String>,
+    },
+    /// Print every near-miss fragment in the puzzle input for a single day.
+    Lint {
+        /// Represents the day to lint.
+        day: u32,
+        /// Represents an input file to lint instead of the puzzle input.
+        input: Option<String>,
+    },
+}
+
+/// Parse a day number from a command line argument.
+fn parse_day(arg: Option<String>) -> Result<u32, String> {
+    let arg = arg.ok_or("missing day")?;
+
+    arg.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day: {arg}"))
+}
+
+/// Parse a year from a command line argument, starting from the first puzzles in 2015.
+fn parse_year(arg: Option<String>) -> Result<u32, String> {
+    let arg = arg.ok_or("missing year")?;
+
+    arg.parse()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| format!("invalid year: {arg}"))
+}
+
+/// Returns the solutions registered for the given year.
+fn find_year(year: u32) -> Result<&'static Year, String> {
+    runner::find_year(year).ok_or_else(|| format!("no solutions registered for {year}"))
+}
+
+/// Returns the tool picked out by `select` for the given day, naming the missing tool otherwise.
+fn find_tool<F, T>(year: &Year, day: u32, name: &str, select: F) -> Result<T, String>
+where
+    F: Fn(Tool) -> Option<T>,
+{
+    year.tool(day, select)
+        .ok_or_else(|| format!("no {name} available for {} day {day}", year.year))
+}
+
+/// Returns the tool picked out by `select` for the given day, or for every day which has one.
+///
+/// A single day must have the tool, while days without it are skipped otherwise.
+fn find_tools<F, T>(
+    year: &Year,
+    day: Option<u32>,
+    name: &str,
+    select: F,
+) -> Result<Vec<(u32, T)>, String>
+where
+    F: Fn(Tool) -> Option<T>,
+{
+    match day {
+        Some(day) => Ok(vec![(day, find_tool(year, day, name, select)?)]),
+        None => Ok(year
+            .tools
+            .iter()
+            .filter_map(|(day, tool)| Some((*day, select(*tool)?)))
+            .collect()),
+    }
+}
+
+/// Parse a part number from a command line argument.
+fn parse_part(arg: Option<String>) -> Result<u32, String> {
+    arg.and_then(|part| part.parse().ok())
+        .filter(|part| [1, 2].contains(part))
+        .ok_or("invalid part".to_string())
+}
+
+/// Read the given input file, or fall back to the puzzle input for the given day.
+fn read_input(year: u32, day: u32, input: Option<String>) -> Result<String, String> {
+    match input {
+        Some(path) => fs::read_to_string(&path).map_err(|err| format!("{path}: {err}")),
+        None => runner::load_input(year, day)
+            .map_err(|err| format!("{}: {err}", runner::input_path(year, day).display())),
+    }
+}
+
+/// Read and parse a saved puzzle description.
+fn read_description(path: &str) -> Result<Description, String> {
+    let html = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let description = Description::parse(&html);
+
+    match description.parts.is_empty() {
+        true => Err(format!("{path} has no puzzle description")),
+        false => Ok(description),
+    }
+}
+
+impl Command {
+    /// Parse a command from the command line arguments, excluding the program name.
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            // Without a subcommand, run every solution.
+            None => Ok(Self::Run {
+                day: None,
+                trace: false,
+                visualize: false,
+            }),
+            // Run every solution, or only those of the given day.
+            Some("run") => {
+                let mut day = None;
+                let mut trace = false;
+                let mut visualize = false;
+
+                // Consume the optional day and any flags in order.
+                for arg in args {
+                    match arg.as_str() {
+                        "--trace" => trace = true,
+                        "--visualize" => visualize = true,
+                        _ if day.is_none() => day = Some(parse_day(Some(arg))?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::Run {
+                    day,
+                    trace,
+                    visualize,
+                })
+            }
+            // Download the input of the given day.
+            Some("fetch") => {
+                let day = parse_day(args.next())?;
+
+                match args.next() {
+                    Some(arg) => Err(format!("unexpected argument: {arg}")),
+                    None => Ok(Self::Fetch { day }),
+                }
+            }
+            // Submit the answer to the given part of the given day.
+            Some("submit") => {
+                let day = parse_day(args.next())?;
+                let part = parse_part(args.next())?;
+
+                match args.next() {
+                    Some(arg) => Err(format!("unexpected argument: {arg}")),
+                    None => Ok(Self::Submit { day, part }),
+                }
+            }
+            // Generate the module of the given day.
+            Some("new") => {
+                let day = parse_day(args.next())?;
+
+                let mut html = None;
+
+                // Consume any remaining flags in order.
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--html" => html = Some(args.next().ok_or("missing html path")?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::New { day, html })
+            }
+            // Extract the samples of the given day.
+            Some("samples") => {
+                let day = parse_day(args.next())?;
+                let html = args.next().ok_or("missing html path")?;
+
+                match args.next() {
+                    Some(arg) => Err(format!("unexpected argument: {arg}")),
+                    None => Ok(Self::Samples { day, html }),
+                }
+            }
+            // Analyze the input of the given day.
+            Some("analyze") => {
+                let day = parse_day(args.next())?;
+
+                let mut json = false;
+                let mut input = None;
+
+                // Consume any remaining flags in order.
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--json" => json = true,
+                        "--input" => input = Some(args.next().ok_or("missing input path")?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::Analyze { day, json, input })
+            }
+            // Export an image of the input of the given day.
+            Some("export") => {
+                let day = parse_day(args.next())?;
+                let output = args.next().ok_or("missing output path")?;
+
+                let mut part = 1;
+                let mut input = None;
+
+                // Consume any remaining flags in order.
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--part" => part = parse_part(args.next())?,
+                        "--input" => input = Some(args.next().ok_or("missing input path")?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::Export {
+                    day,
+                    part,
+                    output,
+                    input,
+                })
+            }
+            // Draw the rules in the input of the given day.
+            Some("graph") => {
+                let day = parse_day(args.next())?;
+
+                let mut update = None;
+                let mut mermaid = false;
+                let mut input = None;
+
+                // Consume any remaining flags in order.
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--update" => {
+                            update = Some(
+                                args.next()
+                                    .and_then(|update| update.parse().ok())
+                                    .filter(|update| *update > 0)
+                                    .ok_or("invalid update")?,
+                            )
+                        }
+                        "--mermaid" => mermaid = true,
+                        "--input" => input = Some(args.next().ok_or("missing input path")?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::Graph {
+                    day,
+                    update,
+                    mermaid,
+                    input,
+                })
+            }
+            // Lint the input of the given day.
+            Some("lint") => {
+                let day = parse_day(args.next())?;
+
+                let mut input = None;
+
+                // Consume any remaining flags in order.
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--input" => input = Some(args.next().ok_or("missing input path")?),
+                        _ => return Err(format!("unexpected argument: {arg}")),
+                    }
+                }
+
+                Ok(Self::Lint { day, input })
+            }
+            Some(command) => Err(format!("unknown command: {command}")),
+        }
+    }
+
+    /// Execute the command for the given year, printing its output to stdout.
+    pub fn execute(self, year: u32) -> Result<(), String> {
+        match self {
+            Self::Run {
+                day,
+                trace,
+                visualize,
+            } => {
+                let year = find_year(year)?;
+
+                // Tracing is only supported by days evaluated on a machine.
+                if trace {
+                    let traces = find_tools(year, day, "trace", |tool| match tool {
+                        Tool::Trace(trace) => Some(trace),
+                        _ => None,
+                    })?;
+
+                    traces.into_iter().try_for_each(|(day, trace)| {
+                        let input = read_input(year.year, day, None)?;
+                        println!("{}\n", trace(&input)?);
+                        Ok::<_, String>(())
+                    })?;
+                }
+
+                // Visualization is only supported by days solved on a grid.
+                if visualize {
+                    let visualizations =
+                        find_tools(year, day, "visualization", |tool| match tool {
+                            Tool::Visualize(visualize) => Some(visualize),
+                            _ => None,
+                        })?;
+
+                    visualizations
+                        .into_iter()
+                        .try_for_each(|(day, visualize)| {
+                            let input = read_input(year.year, day, None)?;
+                            println!("{}\n", visualize(&input, Style::detect())?);
+                            Ok::<_, String>(())
+                        })?;
+                }
+
+                runner::run(year, day).map_err(|err| err.to_string())
+            }
+            Self::Fetch { day } => {
+                let client = Client::new(Curl, client::load_session(), year);
+
+                match client.fetch_input(day, &runner::input_path(year, day))? {
+                    Fetched::Cached(path) => println!("{} is already cached", path.display()),
+                    Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+                }
+
+                Ok(())
+            }
+            Self::Submit { day, part } => {
+                let solution = find_year(year)?
+                    .solutions
+                    .iter()
+                    // Alternative solvers give the same answer, so only the main one is submitted.
+                    .find(|solution| {
+                        (solution.day, solution.part, solution.name) == (day, part, None)
+                    })
+                    .ok_or_else(|| format!("no solution for day {day} part {part}"))?;
+                let input = runner::load_input(year, day).map_err(|err| err.to_string())?;
+                let answer = (solution.solve)(&input)
+                    .map_err(|err| format!("no answer for day {day} part {part}: {err}"))?;
+
+                let path = runner::history_path(year);
+                let mut history = History::load(&path)?;
+                let time = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_err(|err| err.to_string())?
+                    .as_secs();
+
+                // Refuse answers which earlier verdicts already rule out.
+                history
+                    .check(day, part, &answer, time)
+                    .map_err(|refusal| format!("not submitting {answer}: {refusal}"))?;
+
+                let client = Client::new(Curl, client::load_session(), year);
+                let verdict = client.submit_answer(day, part, &answer)?;
+
+                // Judged answers bound later guesses and rate limits delay them, while a
+                // part found to be solved refuses every later guess, whatever the answer.
+                history.record(Guess {
+                    time,
+                    day,
+                    part,
+                    answer: answer.clone(),
+                    verdict,
+                });
+                history.save(&path).map_err(|err| err.to_string())?;
+
+                match verdict {
+                    Verdict::Correct => println!("{answer} is correct"),
+                    Verdict::TooHigh => println!("{answer} is too high"),
+                    Verdict::TooLow => println!("{answer} is too low"),
+                    Verdict::Wrong => println!("{answer} is wrong"),
+                    Verdict::RateLimited { wait } => {
+                        println!("rate limited, wait {}s", wait.as_secs())
+                    }
+                    Verdict::AlreadySolved => println!("part {part} is already solved"),
+                }
+
+                Ok(())
+            }
+            Self::New { day, html } => {
+                let description = match html {
+                    Some(path) => Some(read_description(&path)?),
+                    None => None,
+                };
+
+                scaffold::scaffold(Path::new("."), year, day, description.as_ref())?
+                    .iter()
+                    .for_each(|path| println!("wrote {}", path.display()));
+
+                Ok(())
+            }
+            Self::Samples { day, html } => {
+                let dir = scaffold::day_dir(Path::new("."), year, day);
+                if !dir.exists() {
+                    return Err(format!(
+                        "{} does not exist, run `new {day}` first",
+                        dir.display()
+                    ));
+                }
+
+                read_description(&html)?
+                    .write(&dir.join("samples"))?
+                    .iter()
+                    .for_each(|path| println!("wrote {}", path.display()));
+
+                Ok(())
+            }
+            Self::Analyze { day, json, input } => {
+                let analyze = find_tool(find_year(year)?, day, "analysis", |tool| match tool {
+                    Tool::Analyze(analyze) => Some(analyze),
+                    _ => None,
+                })?;
+                let input = read_input(year, day, input)?;
+
+                println!("{}", analyze(&input, json)?);
+                Ok(())
+            }
+            Self::Export {
+                day,
+                part,
+                output,
+                input,
+            } => {
+                let export = find_tool(find_year(year)?, day, "export", |tool| match tool {
+                    Tool::Export(export) => Some(export),
+                    _ => None,
+                })?;
+                let input = read_input(year, day, input)?;
+
+                let path = Path::new(&output);
+                let format =
+                    Format::from_path(path).ok_or("output must end in .svg, .ppm or .png")?;
+
+                let image = export(&input, part, format)?;
+                fs::write(path, image).map_err(|err| err.to_string())
+            }
+            Self::Graph {
+                day,
+                update,
+                mermaid,
+                input,
+            } => {
+                let graph = find_tool(find_year(year)?, day, "graph", |tool| match tool {
+                    Tool::Graph(graph) => Some(graph),
+                    _ => None,
+                })?;
+                let input = read_input(year, day, input)?;
+
+                println!("{}", graph(&input, update, mermaid)?);
+                Ok(())
+            }
+            Self::Lint { day, input } => {
+                let lint = find_tool(find_year(year)?, day, "linter", |tool| match tool {
+                    Tool::Lint(lint) => Some(lint),
+                    _ => None,
+                })?;
+                let input = read_input(year, day, input)?;
+
+                println!("{}", lint(&input)?);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Command::Run {
+                day: None,
+                trace: false,
+                visualize: false
+            })
+        );
+        assert_eq!(
+            parse(&["run", "3", "--trace"]),
+            Ok(Command::Run {
+                day: Some(3),
+                trace: true,
+                visualize: false
+            })
+        );
+        assert_eq!(
+            parse(&["run", "--visualize", "4"]),
+            Ok(Command::Run {
+                day: Some(4),
+                trace: false,
+                visualize: true
+            })
+        );
+        assert_eq!(
+            parse(&["analyze", "2", "--json"]),
+            Ok(Command::Analyze {
+                day: 2,
+                json: true,
+                input: None
+            })
+        );
+        assert_eq!(
+            parse(&["lint", "3", "--input", "memory.txt"]),
+            Ok(Command::Lint {
+                day: 3,
+                input: Some("memory.txt".to_string())
+            })
+        );
+        assert_eq!(
+            parse(&["export", "4", "day4.png", "--part", "2"]),
+            Ok(Command::Export {
+                day: 4,
+                part: 2,
+                output: "day4.png".to_string(),
+                input: None
+            })
+        );
+        assert!(parse(&["export", "4", "day4.png", "--part", "3"]).is_err());
+        assert_eq!(
+            parse(&["graph", "5", "--update", "4", "--mermaid"]),
+            Ok(Command::Graph {
+                day: 5,
+                update: Some(4),
+                mermaid: true,
+                input: None
+            })
+        );
+        assert!(parse(&["graph", "5", "--update", "0"]).is_err());
+        assert_eq!(parse(&["fetch", "6"]), Ok(Command::Fetch { day: 6 }));
+        assert_eq!(
+            parse(&["submit", "5", "2"]),
+            Ok(Command::Submit { day: 5, part: 2 })
+        );
+        assert!(parse(&["submit", "5", "3"]).is_err());
+        assert_eq!(
+            parse(&["new", "6", "--html", "day6.html"]),
+            Ok(Command::New {
+                day: 6,
+                html: Some("day6.html".to_string())
+            })
+        );
+        assert_eq!(
+            parse(&["samples", "6", "day6.html"]),
+            Ok(Command::Samples {
+                day: 6,
+                html: "day6.html".to_string()
+            })
+        );
+        assert!(parse(&["run", "26"]).is_err());
+        assert!(parse(&["analyze", "2", "--csv"]).is_err());
+    }
+
+    #[test]
+    fn tools() {
+        let year = runner::find_year(2024).unwrap();
+        let visualize = |tool| match tool {
+            Tool::Visualize(visualize) => Some(visualize),
+            _ => None,
+        };
+
+        // Without a day, only the days registering the tool are used.
+        let days = find_tools(year, None, "visualization", visualize)
+            .map(|tools| tools.into_iter().map(|(day, _)| day).collect::<Vec<_>>());
+        assert_eq!(days, Ok(vec![4]));
+        assert_eq!(
+            find_tools(year, Some(3), "visualization", visualize).map(|tools| tools.len()),
+            Err("no visualization available for 2024 day 3".to_string())
+        );
+
+        let lint = find_tool(year, 3, "linter", |tool| match tool {
+            Tool::Lint(lint) => Some(lint),
+            _ => None,
+        });
+        assert_eq!(
+            lint.and_then(|lint| lint("mul(1,2]")),
+            Ok("warning: mul: unexpected character `]` in arguments\n --> 1:1\n  |\n1 | mul(1,2]\n  | ^^^^^^^^\n\n1 near misses found".to_string())
+        );
+    }
+
+    #[test]
+    fn years() {
+        let args = |args: &[&str]| Args::parse(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            args(&["fetch", "--year", "2023", "6"]),
+            Ok(Args {
+                year: 2023,
+                command: Command::Fetch { day: 6 }
+            })
+        );
+        assert_eq!(
+            args(&["fetch", "6"]).map(|args| args.year),
+            Ok(runner::latest_year())
+        );
+        assert!(args(&["fetch", "6", "--year", "1999"]).is_err());
+        assert!(args(&["fetch", "6", "--year"]).is_err());
+    }
+}
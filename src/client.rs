@@ -0,0 +1,492 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    str::FromStr,
+    time::Duration,
+};
+
+/// Represents the root of every puzzle page.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Represents the user agent sent with every request, as requested by the puzzle author.
+pub const USER_AGENT: &str = "github.com/thatmisaki/aoc-2024";
+
+/// Represents the environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Represents the file holding the session token, relative to the working directory.
+pub const SESSION_FILE: &str = ".session";
+
+/// Represents the method of an HTTP request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Represents a request for a page.
+    Get,
+    /// Represents a request submitting a form.
+    Post,
+}
+
+/// Represents an HTTP request to the puzzle website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// Represents the method of the request.
+    pub method: Method,
+    /// Represents the absolute URL of the request.
+    pub url: String,
+    /// Represents the session token sent as a cookie.
+    pub session: String,
+    /// Represents the URL-encoded form fields sent as the body of a `POST` request.
+    pub form: Vec<(String, String)>,
+}
+
+/// Represents the status and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// Represents the status code of the response.
+    pub status: u16,
+    /// Represents the body of the response, decoded as text.
+    pub body: String,
+}
+
+/// Sends HTTP requests, so that the network can be replaced in tests.
+pub trait Http {
+    /// Send the request and wait for its response.
+    fn send(&self, request: &Request) -> io::Result<Response>;
+}
+
+/// Sends requests through the `curl` command line tool, which handles TLS.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Curl;
+
+impl Http for Curl {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        let mut command = Command::new("curl");
+        command
+            // Redirects are not followed, as the website only redirects to its login page.
+            .args(["--silent", "--show-error"])
+            .args(["--user-agent", USER_AGENT])
+            // Read the cookie header from stdin, so that the token never appears in `ps`.
+            .args(["--header", "@-"])
+            // Append the status code on its own line after the body.
+            .args(["--write-out", "\n%{http_code}"]);
+
+        request.form.iter().for_each(|(key, value)| {
+            command.args(["--data-urlencode", &format!("{key}={value}")]);
+        });
+
+        if request.method == Method::Post && request.form.is_empty() {
+            command.args(["--request", "POST"]);
+        }
+
+        let mut child = command
+            .arg(&request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", request.session)?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(io::Error::other(message));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output
+            .rsplit_once('\n')
+            .ok_or_else(|| io::Error::other("missing status code"))?;
+
+        Ok(Response {
+            status: status
+                .trim()
+                .parse()
+                .map_err(|_| io::Error::other("invalid status code"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Returns the session token from its first non-blank line, if any.
+fn parse_session(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Load the session token from `AOC_SESSION`, or from the `.session` file.
+pub fn load_session() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .and_then(|session| parse_session(&session))
+        .or_else(|| parse_session(&fs::read_to_string(SESSION_FILE).ok()?))
+}
+
+/// Represents where a puzzle input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// Represents an input which was already cached at the given path.
+    Cached(PathBuf),
+    /// Represents an input which was downloaded to the given path.
+    Downloaded(PathBuf),
+}
+
+/// Represents the judgement of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Represents the right answer.
+    Correct,
+    /// Represents a wrong answer which is greater than the right one.
+    TooHigh,
+    /// Represents a wrong answer which is less than the right one.
+    TooLow,
+    /// Represents a wrong answer, without a hint in either direction.
+    Wrong,
+    /// Represents an answer which was not judged, because another was given too recently.
+    RateLimited {
+        /// Represents how long to wait before submitting again.
+        wait: Duration,
+    },
+    /// Represents an answer which was not judged, because the part is already solved.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Returns the verdict given by the body of an answer page, if it is recognised.
+    pub fn parse(body: &str) -> Option<Self> {
+        // Only the main article holds the message, the rest of the page is navigation.
+        let article = body
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(body, |(article, _)| article);
+
+        if article.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if article.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if article.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if article.contains("That's not the right answer") {
+            Some(Self::Wrong)
+        } else if article.contains("You gave an answer too recently") {
+            Some(Self::RateLimited {
+                wait: parse_wait(article).unwrap_or(Duration::from_secs(60)),
+            })
+        } else if article.contains("Did you already complete it") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the wait given by a message like "You have 1m 5s left to wait", if any.
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        // Sum each component of the wait, in seconds.
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value = value.parse::<u64>().ok()?;
+
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited { wait } => write!(f, "rate-limited:{}", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(verdict: &str) -> Result<Self, Self::Err> {
+        match verdict {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            "already-solved" => Ok(Self::AlreadySolved),
+            _ => verdict
+                .strip_prefix("rate-limited:")
+                .and_then(|wait| wait.parse().ok())
+                .map(|wait| Self::RateLimited {
+                    wait: Duration::from_secs(wait),
+                })
+                .ok_or_else(|| format!("unknown verdict: {verdict}")),
+        }
+    }
+}
+
+/// Client for the puzzle website, authenticated with a session token.
+#[derive(Debug, Clone)]
+pub struct Client<H> {
+    /// Represents the transport used to send requests.
+    pub http: H,
+    /// Represents the session token, which is only required once a request is sent.
+    pub session: Option<String>,
+    /// Represents the year of every puzzle.
+    pub year: u32,
+}
+
+impl<H: Http> Client<H> {
+    /// Creates a client for the puzzles of the given year.
+    pub fn new(http: H, session: Option<String>, year: u32) -> Self {
+        Client {
+            http,
+            session,
+            year,
+        }
+    }
+
+    /// Send a request to the given path of the puzzle website.
+    pub fn send(
+        &self,
+        method: Method,
+        path: &str,
+        form: Vec<(String, String)>,
+    ) -> Result<Response, String> {
+        let session = self.session.clone().ok_or(format!(
+            "missing session token, set {SESSION_VAR} or write it to {SESSION_FILE}"
+        ))?;
+
+        let request = Request {
+            method,
+            url: format!("{BASE_URL}/{}/{path}", self.year),
+            session,
+            form,
+        };
+
+        self.http
+            .send(&request)
+            .map_err(|err| format!("request failed: {err}"))
+    }
+
+    /// Download the input of the given day to the given path, unless it is already cached.
+    pub fn fetch_input(&self, day: u32, path: &Path) -> Result<Fetched, String> {
+        if path.exists() {
+            return Ok(Fetched::Cached(path.to_path_buf()));
+        }
+
+        let response = self.send(Method::Get, &format!("day/{day}/input"), Vec::new())?;
+        check_status(day, response.status)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        fs::write(path, response.body).map_err(|err| err.to_string())?;
+
+        Ok(Fetched::Downloaded(path.to_path_buf()))
+    }
+
+    /// Submit the answer to the given part of the given day, and return its verdict.
+    pub fn submit_answer(&self, day: u32, part: u32, answer: &str) -> Result<Verdict, String> {
+        let form = vec![
+            ("level".to_string(), part.to_string()),
+            ("answer".to_string(), answer.to_string()),
+        ];
+        let response = self.send(Method::Post, &format!("day/{day}/answer"), form)?;
+
+        check_status(day, response.status)?;
+
+        Verdict::parse(&response.body).ok_or("unrecognised answer page".to_string())
+    }
+}
+
+/// Check the status of a response for a page of the given day.
+///
+/// Only a bad request, an unauthorised one or a redirect to the login page mean that the
+/// session token was rejected, any other failure is reported with its status.
+fn check_status(day: u32, status: u16) -> Result<(), String> {
+    match status {
+        200 => Ok(()),
+        404 => Err(format!("day {day} has not been unlocked yet")),
+        400 | 401 | 302 | 303 => Err("session token was rejected".to_string()),
+        status => Err(format!("request failed with HTTP status {status}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::VecDeque};
+
+    use super::*;
+
+    /// Stand-in for the network which replies with canned responses and records every request.
+    #[derive(Debug, Default)]
+    struct Fake {
+        /// Represents the responses still to be sent, in order.
+        responses: RefCell<VecDeque<Response>>,
+        /// Represents every request sent so far.
+        requests: RefCell<Vec<Request>>,
+    }
+
+    impl Fake {
+        /// Creates a fake which replies with the given responses, in order.
+        fn new<I>(responses: I) -> Self
+        where
+            I: IntoIterator<Item = (u16, &'static str)>,
+        {
+            let responses = responses
+                .into_iter()
+                .map(|(status, body)| Response {
+                    status,
+                    body: body.to_string(),
+                })
+                .collect();
+
+            Fake {
+                responses: RefCell::new(responses),
+                requests: Default::default(),
+            }
+        }
+    }
+
+    impl Http for &Fake {
+        fn send(&self, request: &Request) -> io::Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .ok_or_else(|| io::Error::other("no canned response left"))
+        }
+    }
+
+    /// Returns a fresh path inside the temporary directory, unique to the test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2024-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("2024/day1.txt")
+    }
+
+    #[test]
+    fn caching() {
+        let fake = Fake::new([(200, "3   4\n4   3\n")]);
+        let client = Client::new(&fake, Some("token".to_string()), 2024);
+        let path = scratch("caching");
+
+        assert_eq!(
+            client.fetch_input(1, &path),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        // The second fetch is served from the cache without sending a request.
+        assert_eq!(
+            client.fetch_input(1, &path),
+            Ok(Fetched::Cached(path.clone()))
+        );
+        assert_eq!(fake.requests.borrow().len(), 1);
+
+        let request = &fake.requests.borrow()[0];
+        assert_eq!(request.url, "https://adventofcode.com/2024/day/1/input");
+        assert_eq!(request.session, "token");
+
+        // Remove the whole scratch directory, above the year of the input.
+        fs::remove_dir_all(path.ancestors().nth(2).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn failures() {
+        let fake = Fake::new([(404, "Please don't repeatedly request this endpoint")]);
+        let path = scratch("failures");
+
+        let client = Client::new(&fake, None, 2024);
+        assert!(client
+            .fetch_input(1, &path)
+            .unwrap_err()
+            .contains("AOC_SESSION"));
+
+        let client = Client::new(&fake, Some("token".to_string()), 2024);
+        assert_eq!(
+            client.fetch_input(1, &path),
+            Err("day 1 has not been unlocked yet".to_string())
+        );
+        assert!(!path.exists());
+
+        // Only some statuses mean that the session token was rejected.
+        let fake = Fake::new([(400, ""), (401, ""), (302, ""), (503, "")]);
+        let client = Client::new(&fake, Some("token".to_string()), 2024);
+        (0..3).for_each(|_| {
+            assert_eq!(
+                client.fetch_input(1, &path),
+                Err("session token was rejected".to_string())
+            );
+        });
+        assert_eq!(
+            client.submit_answer(1, 1, "11"),
+            Err("request failed with HTTP status 503".to_string())
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn submissions() {
+        let fake = Fake::new([
+            (200, "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>"),
+            (200, "<article><p>That's not the right answer; your answer is too high.</p></article>"),
+            (200, "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>"),
+            (200, "<p>Something else entirely</p>"),
+        ]);
+        let client = Client::new(&fake, Some("token".to_string()), 2024);
+
+        assert_eq!(client.submit_answer(5, 2, "6191"), Ok(Verdict::Correct));
+        assert_eq!(client.submit_answer(5, 2, "9000"), Ok(Verdict::TooHigh));
+        assert_eq!(
+            client.submit_answer(5, 2, "9000"),
+            Ok(Verdict::RateLimited {
+                wait: Duration::from_secs(65)
+            })
+        );
+        assert!(client.submit_answer(5, 2, "9000").is_err());
+
+        let request = &fake.requests.borrow()[0];
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://adventofcode.com/2024/day/5/answer");
+        assert_eq!(
+            request.form,
+            vec![
+                ("level".to_string(), "2".to_string()),
+                ("answer".to_string(), "6191".to_string())
+            ]
+        );
+
+        // Verdicts survive a round trip through their text form.
+        let verdict = Verdict::RateLimited {
+            wait: Duration::from_secs(65),
+        };
+        assert_eq!(verdict.to_string().parse(), Ok(verdict));
+    }
+
+    #[test]
+    fn sessions() {
+        assert_eq!(parse_session("\n  abc123 \n"), Some("abc123".to_string()));
+        assert_eq!(parse_session("  \n"), None);
+    }
+}
@@ -0,0 +1,385 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// Represents a position on a two-dimensional plane, in rows and columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    /// Represents the row of the position, increasing downwards.
+    pub row: isize,
+    /// Represents the column of the position, increasing rightwards.
+    pub col: isize,
+}
+
+/// Represents an offset between two `Point`s, in rows and columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    /// Represents the offset in rows.
+    pub row: isize,
+    /// Represents the offset in columns.
+    pub col: isize,
+}
+
+impl Point {
+    /// Creates a point from its row and column.
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// Returns the sum of the absolute row and column offsets to another point.
+    pub fn manhattan(self, other: Self) -> usize {
+        (other - self).manhattan()
+    }
+
+    /// Returns the largest of the absolute row and column offsets to another point.
+    pub fn chebyshev(self, other: Self) -> usize {
+        (other - self).chebyshev()
+    }
+
+    /// Converts the point into a pair of row and column indices, if both are non-negative.
+    pub fn to_cursor(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+
+    /// Converts the point into a row-major index into a grid with the given dimensions.
+    ///
+    /// Returns `None` if the point lies outside of the grid.
+    pub fn to_index(self, rows: usize, cols: usize) -> Option<usize> {
+        let (row, col) = self.to_cursor()?;
+
+        // Assert that the point lies within the bounds of the grid.
+        (row < rows && col < cols).then_some((row * cols) + col)
+    }
+
+    /// Converts a row-major index into a grid with the given number of columns into a point.
+    pub fn from_index(index: usize, cols: usize) -> Self {
+        Point::new((index / cols) as isize, (index % cols) as isize)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl Vector {
+    /// Creates a vector from its row and column offsets.
+    pub const fn new(row: isize, col: isize) -> Self {
+        Vector { row, col }
+    }
+
+    /// Returns the sum of the absolute row and column offsets.
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+
+    /// Returns the largest of the absolute row and column offsets.
+    pub fn chebyshev(self) -> usize {
+        self.row.unsigned_abs().max(self.col.unsigned_abs())
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Vector::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Add<Vector> for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Vector::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vector::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/// Represents an inclusive rectangle of `Point`s, such as the bounding box of a set of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// Represents the corner with the smallest row and column.
+    pub min: Point,
+    /// Represents the corner with the largest row and column.
+    pub max: Point,
+}
+
+impl Bounds {
+    /// Creates the bounding box containing only the given point.
+    pub fn from_point(point: Point) -> Self {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Returns the smallest bounding box containing every given point, if any.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut points = points.into_iter();
+        let first = Self::from_point(points.next()?);
+
+        Some(points.fold(first, Self::include))
+    }
+
+    /// Returns the smallest bounding box containing both this one and the given point.
+    pub fn include(self, point: Point) -> Self {
+        Bounds {
+            min: Point::new(self.min.row.min(point.row), self.min.col.min(point.col)),
+            max: Point::new(self.max.row.max(point.row), self.max.col.max(point.col)),
+        }
+    }
+
+    /// Returns the bounding box grown by the given margin on every side.
+    pub fn grow(self, margin: isize) -> Self {
+        Bounds {
+            min: self.min - Vector::new(margin, margin),
+            max: self.max + Vector::new(margin, margin),
+        }
+    }
+
+    /// Returns whether the given point lies within the bounding box.
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.row..=self.max.row).contains(&point.row)
+            && (self.min.col..=self.max.col).contains(&point.col)
+    }
+
+    /// Returns the number of rows covered by the bounding box.
+    pub fn rows(&self) -> usize {
+        (self.max.row - self.min.row + 1) as usize
+    }
+
+    /// Returns the number of columns covered by the bounding box.
+    pub fn cols(&self) -> usize {
+        (self.max.col - self.min.col + 1) as usize
+    }
+
+    /// Iterate over every point within the bounding box in row-major order.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+
+        (min.row..=max.row)
+            .flat_map(move |row| (min.col..=max.col).map(move |col| Point::new(row, col)))
+    }
+}
+
+/// Represents one of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    /// Represents the direction towards lower rows.
+    Up,
+    /// Represents the direction towards higher columns.
+    Right,
+    /// Represents the direction towards higher rows.
+    Down,
+    /// Represents the direction towards lower columns.
+    Left,
+}
+
+impl Direction4 {
+    /// Represents every direction, in clockwise order starting upwards.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Iterate over every direction, in clockwise order starting upwards.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the direction rotated a quarter turn clockwise.
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Returns the direction rotated a quarter turn counter-clockwise.
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the unit vector pointing in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(-1, 0),
+            Self::Right => Vector::new(0, 1),
+            Self::Down => Vector::new(1, 0),
+            Self::Left => Vector::new(0, -1),
+        }
+    }
+}
+
+/// Represents one of the eight orthogonal and diagonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    /// Represents the direction towards lower rows.
+    Up,
+    /// Represents the direction towards lower rows and higher columns.
+    UpRight,
+    /// Represents the direction towards higher columns.
+    Right,
+    /// Represents the direction towards higher rows and higher columns.
+    DownRight,
+    /// Represents the direction towards higher rows.
+    Down,
+    /// Represents the direction towards higher rows and lower columns.
+    DownLeft,
+    /// Represents the direction towards lower columns.
+    Left,
+    /// Represents the direction towards lower rows and lower columns.
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Represents every direction, in clockwise order starting upwards.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Iterate over every direction, in clockwise order starting upwards.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the direction rotated an eighth of a turn clockwise.
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns the direction rotated an eighth of a turn counter-clockwise.
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns whether the direction is diagonal.
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// Returns the vector pointing one step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(-1, 0),
+            Self::UpRight => Vector::new(-1, 1),
+            Self::Right => Vector::new(0, 1),
+            Self::DownRight => Vector::new(1, 1),
+            Self::Down => Vector::new(1, 0),
+            Self::DownLeft => Vector::new(1, -1),
+            Self::Left => Vector::new(0, -1),
+            Self::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction4::Up.rotate_cw(), Direction4::Right);
+        assert_eq!(Direction4::Up.rotate_ccw(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        assert_eq!(Direction8::UpLeft.rotate_cw(), Direction8::Up);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+
+        // Opposite directions always cancel each other out.
+        assert!(Direction8::iter()
+            .all(|dir| dir.vector() + dir.reverse().vector() == Vector::default()));
+        assert_eq!(
+            Direction8::iter().filter(|dir| dir.is_diagonal()).count(),
+            4
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let (lhs, rhs) = (Point::new(1, -2), Point::new(-3, 4));
+
+        assert_eq!(lhs.manhattan(rhs), 10);
+        assert_eq!(lhs.chebyshev(rhs), 6);
+        assert_eq!(lhs + (rhs - lhs), rhs);
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = Bounds::from_points([Point::new(2, -1), Point::new(-1, 3)]).unwrap();
+
+        assert_eq!(
+            (bounds.min, bounds.max),
+            (Point::new(-1, -1), Point::new(2, 3))
+        );
+        assert_eq!((bounds.rows(), bounds.cols()), (4, 5));
+        assert_eq!(bounds.iter_points().count(), 20);
+        assert!(!bounds.contains(Point::new(3, 0)));
+        assert!(bounds.grow(1).contains(Point::new(3, 0)));
+    }
+
+    #[test]
+    fn indices() {
+        assert_eq!(Point::new(1, 2).to_index(3, 4), Some(6));
+        assert_eq!(Point::new(1, 4).to_index(3, 4), None);
+        assert_eq!(Point::new(-1, 0).to_index(3, 4), None);
+        assert_eq!(Point::from_index(6, 4), Point::new(1, 2));
+    }
+}
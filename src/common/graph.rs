@@ -0,0 +1,362 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::common::search::bfs;
+
+/// Directed graph over nodes of any hashable type, without parallel edges.
+///
+/// Nodes are kept in the order they were first added, which every traversal follows,
+/// so that results are deterministic.
+#[derive(Debug, Clone)]
+pub struct DiGraph<T> {
+    /// Represents every node, in the order it was first added.
+    nodes: Vec<T>,
+    /// Represents the position of every node in `nodes`.
+    indices: HashMap<T, usize>,
+    /// Represents the positions of the successors of every node, in the order they were added.
+    edges: Vec<Vec<usize>>,
+}
+
+impl<T> Default for DiGraph<T> {
+    fn default() -> Self {
+        DiGraph {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+// Construction method implementations.
+impl<T> DiGraph<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Creates a graph without any nodes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a graph from a list of edges, adding every node they mention.
+    pub fn from_edges<I>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (T, T)>,
+    {
+        edges
+            .into_iter()
+            .fold(Self::new(), |mut graph, (from, to)| {
+                graph.add_edge(from, to);
+                graph
+            })
+    }
+
+    /// Adds the given node if it is not already present, returning its position.
+    pub fn add_node(&mut self, node: T) -> usize {
+        if let Some(index) = self.indices.get(&node) {
+            return *index;
+        }
+
+        self.indices.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge between the given nodes, adding either node if necessary.
+    ///
+    /// Returns whether the edge was not already present.
+    pub fn add_edge(&mut self, from: T, to: T) -> bool {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+
+        let fresh = !self.edges[from].contains(&to);
+        if fresh {
+            self.edges[from].push(to);
+        }
+
+        fresh
+    }
+}
+
+// Query method implementations.
+impl<T> DiGraph<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns whether the given node is present.
+    pub fn contains(&self, node: &T) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    /// Returns whether there is an edge between the given nodes.
+    pub fn contains_edge(&self, from: &T, to: &T) -> bool {
+        let edge = self.indices.get(from).zip(self.indices.get(to));
+
+        edge.is_some_and(|(from, to)| self.edges[*from].contains(to))
+    }
+
+    /// Iterate over every node, in the order it was first added.
+    pub fn nodes(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter()
+    }
+
+    /// Iterate over every edge, grouped by the node it leaves.
+    pub fn edges(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .map(move |to| (&self.nodes[from], &self.nodes[*to]))
+            })
+    }
+
+    /// Iterate over the successors of the given node, which is empty if it is not present.
+    pub fn successors(&self, node: &T) -> impl Iterator<Item = &T> {
+        self.indices
+            .get(node)
+            .into_iter()
+            .flat_map(|index| &self.edges[*index])
+            .map(|index| &self.nodes[*index])
+    }
+}
+
+// Derived graph method implementations.
+impl<T> DiGraph<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Returns the graph with the direction of every edge flipped.
+    pub fn reverse(&self) -> Self {
+        // Add every node first, so that nodes keep their order.
+        let graph = self.nodes().cloned().fold(Self::new(), |mut graph, node| {
+            graph.add_node(node);
+            graph
+        });
+
+        self.edges().fold(graph, |mut graph, (from, to)| {
+            graph.add_edge(to.clone(), from.clone());
+            graph
+        })
+    }
+
+    /// Returns the subgraph of the given nodes, with every edge between them.
+    ///
+    /// Nodes which are not present in this graph are added without any edges.
+    pub fn induced<I>(&self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let graph = nodes.into_iter().fold(Self::new(), |mut graph, node| {
+            graph.add_node(node);
+            graph
+        });
+
+        // Keep only the edges whose ends are both in the subgraph.
+        let edges = self
+            .edges()
+            .filter(|(from, to)| graph.contains(from) && graph.contains(to))
+            .map(|(from, to)| (from.clone(), to.clone()))
+            .collect::<Vec<_>>();
+
+        edges.into_iter().fold(graph, |mut graph, (from, to)| {
+            graph.add_edge(from, to);
+            graph
+        })
+    }
+
+    /// Returns every node reachable from the given node, including itself.
+    pub fn reachable(&self, node: &T) -> HashSet<T> {
+        if !self.contains(node) {
+            return HashSet::new();
+        }
+
+        let paths = bfs([node.clone()], |node| {
+            self.successors(node).cloned().collect::<Vec<_>>()
+        });
+
+        paths.costs.into_keys().collect()
+    }
+
+    /// Returns the transitive closure, with an edge wherever a path of one or more edges exists.
+    pub fn closure(&self) -> Self {
+        let graph = self.nodes().cloned().fold(Self::new(), |mut graph, node| {
+            graph.add_node(node);
+            graph
+        });
+
+        // Connect every node to everything reachable from any of its successors.
+        self.nodes().fold(graph, |graph, from| {
+            self.successors(from)
+                .flat_map(|next| self.reachable(next))
+                .fold(graph, |mut graph, to| {
+                    graph.add_edge(from.clone(), to);
+                    graph
+                })
+        })
+    }
+
+    /// Returns every strongly connected component, in reverse topological order.
+    ///
+    /// Every node belongs to exactly one component, and no edge leaves a component
+    /// towards one listed after it.
+    pub fn components(&self) -> Vec<Vec<T>> {
+        let mut order = vec![None; self.len()];
+        let mut lowlink = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut counter = 0;
+
+        // Run Tarjan's algorithm from every unvisited node, with an explicit call stack
+        // holding each node and the position of its next edge to explore.
+        (0..self.len()).for_each(|root| {
+            if order[root].is_some() {
+                return;
+            }
+
+            let mut calls = vec![(root, 0)];
+            while let Some((node, edge)) = calls.pop() {
+                if edge == 0 {
+                    order[node] = Some(counter);
+                    lowlink[node] = counter;
+                    counter += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                match self.edges[node].get(edge) {
+                    // Resume this node at its next edge once the successor has been explored.
+                    Some(&next) => {
+                        calls.push((node, edge + 1));
+
+                        match order[next] {
+                            None => calls.push((next, 0)),
+                            Some(index) if on_stack[next] => {
+                                lowlink[node] = lowlink[node].min(index)
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                    // Every edge has been explored, so the node may be the root of a component.
+                    None => {
+                        if Some(lowlink[node]) == order[node] {
+                            let mut component = Vec::new();
+
+                            while let Some(member) = stack.pop() {
+                                on_stack[member] = false;
+                                component.push(self.nodes[member].clone());
+
+                                if member == node {
+                                    break;
+                                }
+                            }
+
+                            components.push(component);
+                        }
+
+                        if let Some((parent, _)) = calls.last() {
+                            lowlink[*parent] = lowlink[*parent].min(lowlink[node]);
+                        }
+                    }
+                }
+            }
+        });
+
+        components
+    }
+
+    /// Returns every node ordered so that every edge points forwards, if there are no cycles.
+    ///
+    /// Ties are broken by the order in which nodes were added.
+    pub fn topological_sort(&self) -> Option<Vec<T>> {
+        let mut degrees = vec![0; self.len()];
+        self.edges.iter().flatten().for_each(|to| degrees[*to] += 1);
+
+        let mut queue = (0..self.len())
+            .filter(|node| degrees[*node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut sorted = Vec::with_capacity(self.len());
+
+        // Repeatedly remove a node without incoming edges, releasing its successors.
+        while let Some(node) = queue.pop_front() {
+            sorted.push(self.nodes[node].clone());
+
+            self.edges[node].iter().for_each(|next| {
+                degrees[*next] -= 1;
+                if degrees[*next] == 0 {
+                    queue.push_back(*next);
+                }
+            });
+        }
+
+        // Any node left unsorted lies on a cycle.
+        (sorted.len() == self.len()).then_some(sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> DiGraph<u32> {
+        // Two cycles, 1 -> 2 -> 3 -> 1 and 4 <-> 5, joined by 3 -> 4, with 6 on its own.
+        let mut graph = DiGraph::from_edges([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4)]);
+        graph.add_node(6);
+        graph
+    }
+
+    #[test]
+    fn queries() {
+        let graph = graph();
+
+        assert_eq!(graph.len(), 6);
+        assert!(graph.contains_edge(&3, &4));
+        assert!(!graph.contains_edge(&4, &3));
+        assert!(graph.reverse().contains_edge(&4, &3));
+        assert_eq!(graph.successors(&3).collect::<Vec<_>>(), vec![&1, &4]);
+        assert_eq!(graph.reachable(&4), HashSet::from([4, 5]));
+
+        let closure = graph.closure();
+        assert!(closure.contains_edge(&1, &5));
+        assert!(closure.contains_edge(&1, &1));
+        assert!(!closure.contains_edge(&6, &6));
+    }
+
+    #[test]
+    fn components() {
+        let components = graph()
+            .components()
+            .into_iter()
+            .map(|mut component| {
+                component.sort();
+                component
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(components, vec![vec![4, 5], vec![1, 2, 3], vec![6]]);
+    }
+
+    #[test]
+    fn sorting() {
+        let graph = graph();
+        assert_eq!(graph.topological_sort(), None);
+
+        let subgraph = graph.induced([6, 4, 3, 2]);
+        assert_eq!(subgraph.topological_sort(), Some(vec![6, 2, 3, 4]));
+        assert_eq!(subgraph.edges().count(), 2);
+    }
+}
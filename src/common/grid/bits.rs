@@ -0,0 +1,339 @@
+use crate::common::geometry::{Point, Vector};
+
+use super::Grid;
+
+/// Represents the number of cells packed into each word.
+const BITS: usize = u64::BITS as usize;
+
+/// Bounded grid of booleans packed one bit per cell, such as a visited set or a mask.
+///
+/// Every row starts on a fresh word, so that whole rows can be combined and shifted
+/// a word at a time. Bits past the last column are always kept clear.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    /// Represents the number of rows in the grid.
+    pub rows: usize,
+    /// Represents the number of columns in the grid.
+    pub cols: usize,
+    /// Represents the number of words used by each row.
+    stride: usize,
+    /// Represents every row of the grid, packed into consecutive words.
+    words: Vec<u64>,
+}
+
+// Construction method implementations.
+impl BitGrid {
+    /// Creates a grid with the given dimensions where every cell is clear.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let stride = cols.div_ceil(BITS);
+
+        BitGrid {
+            rows,
+            cols,
+            stride,
+            words: vec![0; rows * stride],
+        }
+    }
+
+    /// Creates a grid with the given dimensions where only the given points are set.
+    ///
+    /// Points lying outside of the grid are ignored.
+    pub fn from_points<I>(rows: usize, cols: usize, points: I) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut grid = Self::new(rows, cols);
+        points.into_iter().for_each(|point| {
+            grid.insert(point);
+        });
+
+        grid
+    }
+
+    /// Converts the grid into a `Grid<bool>`, if it has at least one row and column.
+    pub fn to_grid(&self) -> Option<Grid<bool>> {
+        let rows = (0..self.rows)
+            .map(|row| (0..self.cols).map(move |col| self.contains(Point::from((row, col)))));
+
+        Grid::try_from(rows)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let points = grid
+            .iter_cursors()
+            .filter(|point| grid.get(*point) == Some(&true));
+
+        Self::from_points(grid.rows, grid.cols, points)
+    }
+}
+
+// Cell method implementations.
+impl BitGrid {
+    /// Returns the word index and bit mask of the given point, if it lies on the grid.
+    fn locate(&self, point: Point) -> Option<(usize, u64)> {
+        let (row, col) = point.to_cursor()?;
+        (row < self.rows && col < self.cols).then_some(())?;
+
+        Some((row * self.stride + col / BITS, 1 << (col % BITS)))
+    }
+
+    /// Returns the value of the cell at the given point, if it lies on the grid.
+    pub fn get(&self, point: Point) -> Option<bool> {
+        let (index, mask) = self.locate(point)?;
+
+        Some(self.words[index] & mask != 0)
+    }
+
+    /// Returns whether the cell at the given point lies on the grid and is set.
+    pub fn contains(&self, point: Point) -> bool {
+        self.get(point) == Some(true)
+    }
+
+    /// Sets the cell at the given point, returning whether it was previously clear.
+    ///
+    /// Points lying outside of the grid are never set.
+    pub fn insert(&mut self, point: Point) -> bool {
+        self.set(point, true) == Some(false)
+    }
+
+    /// Clears the cell at the given point, returning whether it was previously set.
+    pub fn remove(&mut self, point: Point) -> bool {
+        self.set(point, false) == Some(true)
+    }
+
+    /// Updates the cell at the given point, returning its previous value.
+    pub fn set(&mut self, point: Point, value: bool) -> Option<bool> {
+        let (index, mask) = self.locate(point)?;
+        let previous = self.words[index] & mask != 0;
+
+        match value {
+            true => self.words[index] |= mask,
+            false => self.words[index] &= !mask,
+        }
+
+        Some(previous)
+    }
+}
+
+// Set method implementations.
+impl BitGrid {
+    /// Returns the number of set cells.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns whether no cell is set.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Iterate over every set cell in row-major order.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, word)| {
+                let (row, offset) = (index / self.stride, (index % self.stride) * BITS);
+
+                // Pop the lowest set bit of the word until none remain.
+                std::iter::successors((*word != 0).then_some(*word), |word| {
+                    let rest = word & (word - 1);
+                    (rest != 0).then_some(rest)
+                })
+                .map(move |word| Point::from((row, offset + word.trailing_zeros() as usize)))
+            })
+    }
+
+    /// Combine every word of both grids with the given operation.
+    ///
+    /// Panics if the grids have different dimensions.
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "grids must have equal dimensions"
+        );
+
+        let words = self.words.iter().zip(&other.words);
+        BitGrid {
+            words: words.map(|(lhs, rhs)| f(*lhs, *rhs)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the cells set in either grid.
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs | rhs)
+    }
+
+    /// Returns the cells set in both grids.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs & rhs)
+    }
+
+    /// Returns the cells set in this grid but not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs & !rhs)
+    }
+}
+
+// Shift method implementations.
+impl BitGrid {
+    /// Returns the row starting at the given word index, shifted towards higher columns.
+    ///
+    /// A negative offset shifts towards lower columns instead.
+    fn shift_row(&self, start: usize, offset: isize) -> Vec<u64> {
+        let row = &self.words[start..start + self.stride];
+        let (words, bits) = (offset.unsigned_abs() / BITS, offset.unsigned_abs() % BITS);
+
+        // Read a word of the source row, treating words off either end as clear.
+        let word = |index: Option<usize>| index.and_then(|index| row.get(index)).copied();
+        let word = |index| word(index).unwrap_or(0);
+
+        (0..self.stride)
+            .map(|index| match offset >= 0 {
+                // Bits move upwards, carrying the top of the previous word into this one.
+                true => {
+                    let low = word(index.checked_sub(words));
+                    let carry = word(index.checked_sub(words + 1));
+                    (low << bits) | carry.checked_shr((BITS - bits) as u32).unwrap_or(0)
+                }
+                // Bits move downwards, carrying the bottom of the next word into this one.
+                false => {
+                    let high = word(Some(index + words));
+                    let carry = word(Some(index + words + 1));
+                    (high >> bits) | carry.checked_shl((BITS - bits) as u32).unwrap_or(0)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the grid with every set cell moved by the given offset.
+    ///
+    /// Cells moved past an edge are dropped, and cells moved away from an edge are clear.
+    pub fn shift(&self, offset: Vector) -> Self {
+        let mut grid = Self::new(self.rows, self.cols);
+
+        (0..self.rows)
+            // Pair every source row with its destination, if it remains on the grid.
+            .filter_map(|row| {
+                let target = row.checked_add_signed(offset.row)?;
+                (target < self.rows).then_some((row, target))
+            })
+            .for_each(|(row, target)| {
+                let words = self.shift_row(row * self.stride, offset.col);
+                let start = target * self.stride;
+
+                grid.words[start..start + self.stride].copy_from_slice(&words);
+            });
+
+        // Clear any bits which were shifted past the last column.
+        if self.cols % BITS != 0 {
+            let bits = self.cols % BITS;
+            let mask = (1 << bits) - 1;
+            grid.words
+                .iter_mut()
+                .skip(self.stride - 1)
+                .step_by(self.stride)
+                .for_each(|word| *word &= mask);
+        }
+
+        grid
+    }
+
+    /// Returns the cells reached by moving any set cell by any of the given offsets.
+    ///
+    /// Spreading by the unit vectors of every direction gives the neighbours of the set.
+    pub fn spread<I>(&self, offsets: I) -> Self
+    where
+        I: IntoIterator<Item = Vector>,
+    {
+        offsets
+            .into_iter()
+            .map(|offset| self.shift(offset))
+            .fold(Self::new(self.rows, self.cols), |lhs, rhs| lhs.union(&rhs))
+    }
+}
+
+// Comonad extension for boolean results.
+impl<T> Grid<T> {
+    /// Apply the given predicate to every cursor view of the grid, packing the results.
+    pub fn extend_bits<F>(&self, f: F) -> BitGrid
+    where
+        F: Fn(Grid<T>) -> bool,
+    {
+        let points = self
+            .iter_cursors()
+            .filter(|cursor| f(self.focus(*cursor).unwrap()));
+
+        BitGrid::from_points(self.rows, self.cols, points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::geometry::Direction4;
+
+    #[test]
+    fn cells() {
+        let mut grid = BitGrid::new(3, 70);
+
+        assert!(grid.insert(Point::new(1, 65)));
+        assert!(!grid.insert(Point::new(1, 65)));
+        assert!(!grid.insert(Point::new(1, 70)));
+        assert!(grid.insert(Point::new(2, 0)));
+
+        assert_eq!(grid.count(), 2);
+        assert_eq!(
+            grid.iter_points().collect::<Vec<_>>(),
+            vec![Point::new(1, 65), Point::new(2, 0)]
+        );
+
+        assert!(grid.remove(Point::new(2, 0)));
+        assert_eq!(grid.get(Point::new(2, 0)), Some(false));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn shifts() {
+        let grid = BitGrid::from_points(2, 130, [Point::new(0, 0), Point::new(1, 127)]);
+
+        let right = grid.shift(Vector::new(0, 64));
+        assert_eq!(
+            right.iter_points().collect::<Vec<_>>(),
+            vec![Point::new(0, 64)]
+        );
+
+        let left = grid.shift(Vector::new(-1, -100));
+        assert_eq!(
+            left.iter_points().collect::<Vec<_>>(),
+            vec![Point::new(0, 27)]
+        );
+
+        // The neighbours of a single cell form a diamond without its centre.
+        let centre = BitGrid::from_points(3, 3, [Point::new(1, 1)]);
+        let neighbours = centre.spread(Direction4::iter().map(Direction4::vector));
+        assert_eq!(neighbours.count(), 4);
+        assert!(neighbours.intersection(&centre).is_empty());
+        assert_eq!(neighbours.union(&centre).difference(&centre), neighbours);
+    }
+
+    #[test]
+    fn conversion() {
+        let grid = Grid::try_from([[true, false], [false, true]]).unwrap();
+        let bits = BitGrid::from(&grid);
+
+        assert_eq!(bits.count(), 2);
+        assert_eq!(bits.to_grid(), Some(grid));
+        assert_eq!(BitGrid::new(0, 4).to_grid(), None);
+    }
+}
@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+use crate::common::geometry::Point;
+
+/// Represents the behaviour of a `Grid` when its cursor is moved past an edge.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Boundary<T> {
+    /// Represents a grid where moving past an edge yields no view at all.
+    Reject,
+    /// Represents a grid where moving past an edge stops at the nearest cell on the edge.
+    Clamp,
+    /// Represents a grid where moving past an edge re-enters from the opposite edge.
+    Wrap,
+    /// Represents a grid where every cell past an edge holds the given value.
+    Fill(Rc<T>),
+}
+
+// Implemented by hand since the fill value is shared, so `T` need not be `Clone`.
+impl<T> Clone for Boundary<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Reject => Self::Reject,
+            Self::Clamp => Self::Clamp,
+            Self::Wrap => Self::Wrap,
+            Self::Fill(fill) => Self::Fill(fill.clone()),
+        }
+    }
+}
+
+impl<T> Boundary<T> {
+    /// Resolve a point against a grid with the given dimensions.
+    ///
+    /// Returns the point the cursor should move to, which only lies outside
+    /// of the grid for `Boundary::Fill`.
+    pub fn resolve(&self, point: Point, rows: usize, cols: usize) -> Option<Point> {
+        let (rows, cols) = (rows as isize, cols as isize);
+
+        match self {
+            Self::Reject => point.to_index(rows as usize, cols as usize).map(|_| point),
+            Self::Clamp => Some(Point::new(
+                point.row.clamp(0, rows - 1),
+                point.col.clamp(0, cols - 1),
+            )),
+            Self::Wrap => Some(Point::new(
+                point.row.rem_euclid(rows),
+                point.col.rem_euclid(cols),
+            )),
+            Self::Fill(_) => Some(point),
+        }
+    }
+
+    /// Returns the value held by cells outside of the grid, if any.
+    pub fn fill(&self) -> Option<&T> {
+        match self {
+            Self::Fill(fill) => Some(fill),
+            _ => None,
+        }
+    }
+
+    /// Returns the same behaviour for a grid of another type.
+    ///
+    /// A fill value cannot be converted, so `Boundary::Fill` becomes `Boundary::Reject`.
+    pub fn cast<U>(&self) -> Boundary<U> {
+        match self {
+            Self::Reject | Self::Fill(_) => Boundary::Reject,
+            Self::Clamp => Boundary::Clamp,
+            Self::Wrap => Boundary::Wrap,
+        }
+    }
+}
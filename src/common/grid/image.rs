@@ -0,0 +1,420 @@
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{Grid, Highlight};
+
+/// Represents an opaque colour with 8 bits per channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Colour {
+    /// Represents the red channel.
+    pub r: u8,
+    /// Represents the green channel.
+    pub g: u8,
+    /// Represents the blue channel.
+    pub b: u8,
+}
+
+impl Colour {
+    /// Represents a colour with every channel off.
+    pub const BLACK: Self = Colour::new(0, 0, 0);
+    /// Represents a colour with every channel fully on.
+    pub const WHITE: Self = Colour::new(255, 255, 255);
+    /// Represents the colour used for cells without a highlight.
+    pub const DIM: Self = Colour::new(48, 48, 48);
+
+    /// Creates a colour from its red, green and blue channels.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Colour { r, g, b }
+    }
+
+    /// Returns the colour as a hexadecimal string, such as `#ff8000`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl From<Highlight> for Colour {
+    fn from(highlight: Highlight) -> Self {
+        match highlight {
+            Highlight::Red => Colour::new(220, 50, 47),
+            Highlight::Green => Colour::new(133, 153, 0),
+            Highlight::Yellow => Colour::new(181, 137, 0),
+            Highlight::Blue => Colour::new(38, 139, 210),
+            Highlight::Magenta => Colour::new(211, 54, 130),
+            Highlight::Cyan => Colour::new(42, 161, 152),
+        }
+    }
+}
+
+/// Represents a raster image, where every cell of a grid is drawn as a square of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// Represents the width of the image, in pixels.
+    pub width: usize,
+    /// Represents the height of the image, in pixels.
+    pub height: usize,
+    /// Represents every pixel of the image, in row-major order.
+    pub pixels: Vec<Colour>,
+}
+
+impl Image {
+    /// Creates an image of the grid, drawing each cell as a square of the given size.
+    pub fn from_grid<T, F>(grid: &Grid<T>, scale: usize, f: F) -> Self
+    where
+        F: Fn(&T) -> Colour,
+    {
+        let (width, height) = (grid.cols * scale, grid.rows * scale);
+
+        // Map every pixel back to the cell covering it.
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (y / scale) * grid.cols + (x / scale)))
+            .map(|index| f(&grid.items[index]))
+            .collect();
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Iterate over the red, green and blue channels of every row, one row at a time.
+    fn rows(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        self.pixels
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().flat_map(|c| [c.r, c.g, c.b]).collect())
+    }
+
+    /// Encodes the image as a binary PPM (`P6`) file.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let header = format!("P6\n{} {}\n255\n", self.width, self.height);
+
+        header
+            .into_bytes()
+            .into_iter()
+            .chain(self.rows().flatten())
+            .collect()
+    }
+
+    /// Encodes the image as an uncompressed PNG file.
+    pub fn to_png(&self) -> Vec<u8> {
+        let header = [
+            (self.width as u32).to_be_bytes().as_slice(),
+            (self.height as u32).to_be_bytes().as_slice(),
+            // Use 8 bits per channel, RGB colour, and no interlacing.
+            &[8, 2, 0, 0, 0],
+        ]
+        .concat();
+
+        // Prefix every row with the filter type, which is always none.
+        let data = self
+            .rows()
+            .flat_map(|row| std::iter::once(0).chain(row))
+            .collect::<Vec<_>>();
+
+        [
+            b"\x89PNG\r\n\x1a\n".to_vec(),
+            png_chunk(b"IHDR", &header),
+            png_chunk(b"IDAT", &zlib_stored(&data)),
+            png_chunk(b"IEND", &[]),
+        ]
+        .concat()
+    }
+}
+
+/// Returns the CRC-32 checksum of the given bytes, as used by PNG.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xedb8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+/// Returns the Adler-32 checksum of the given bytes, as used by zlib.
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+/// Wraps the given bytes in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(bytes: &[u8]) -> Vec<u8> {
+    let chunks = bytes.chunks(u16::MAX as usize).collect::<Vec<_>>();
+
+    // An empty stream still needs a single final block.
+    let chunks = match chunks.is_empty() {
+        true => vec![&[][..]],
+        false => chunks,
+    };
+
+    let blocks = chunks.iter().enumerate().flat_map(|(index, chunk)| {
+        let last = (index + 1 == chunks.len()) as u8;
+        let len = chunk.len() as u16;
+
+        [
+            vec![last],
+            len.to_le_bytes().to_vec(),
+            (!len).to_le_bytes().to_vec(),
+            chunk.to_vec(),
+        ]
+        .concat()
+    });
+
+    // Use the default window size and no compression.
+    [0x78, 0x01]
+        .into_iter()
+        .chain(blocks)
+        .chain(adler32(bytes).to_be_bytes())
+        .collect()
+}
+
+/// Encodes a single PNG chunk with its length and checksum.
+fn png_chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let body = [kind.as_slice(), data].concat();
+
+    [
+        (data.len() as u32).to_be_bytes().as_slice(),
+        &body,
+        &crc32(&body).to_be_bytes(),
+    ]
+    .concat()
+}
+
+/// Writes the `<rect>` of every cell in the grid, each of the given size.
+fn svg_cells<T, F>(out: &mut String, grid: &Grid<T>, scale: usize, f: &F)
+where
+    F: Fn(&T) -> Colour,
+{
+    grid.iter_cursors()
+        .zip(grid.iter_items())
+        .for_each(|(point, item)| {
+            let _ = writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{scale}" height="{scale}" fill="{}"/>"#,
+                point.col as usize * scale,
+                point.row as usize * scale,
+                f(item).hex()
+            );
+        });
+}
+
+/// Returns the opening tag of an SVG document sized to fit the grid.
+fn svg_header<T>(grid: &Grid<T>, scale: usize) -> String {
+    let (width, height) = (grid.cols * scale, grid.rows * scale);
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+    )
+}
+
+/// Render the grid as an SVG document, drawing each cell as a square of the given size.
+pub fn svg<T, F>(grid: &Grid<T>, scale: usize, f: F) -> String
+where
+    F: Fn(&T) -> Colour,
+{
+    let mut out = svg_header(grid, scale) + "\n";
+    svg_cells(&mut out, grid, scale, &f);
+
+    out + "</svg>\n"
+}
+
+/// Render the frames as a looping animated SVG document, showing each for the given seconds.
+///
+/// The document is sized to fit the first frame, and is empty if there are no frames.
+pub fn animated_svg<T, F>(frames: &[Grid<T>], scale: usize, seconds: f64, f: F) -> String
+where
+    F: Fn(&T) -> Colour,
+{
+    let Some(first) = frames.first() else {
+        return String::new();
+    };
+
+    let count = frames.len();
+    let mut out = svg_header(first, scale) + "\n";
+
+    frames.iter().enumerate().for_each(|(index, frame)| {
+        let (start, end) = (index as f64 / count as f64, (index + 1) as f64 / count as f64);
+
+        // Show the frame only during its own slice of the loop.
+        let (values, times) = match index {
+            0 => ("visible;hidden".to_string(), format!("0;{end}")),
+            _ => ("hidden;visible;hidden".to_string(), format!("0;{start};{end}")),
+        };
+
+        let _ = writeln!(out, r#"<g visibility="hidden">"#);
+        let _ = writeln!(
+            out,
+            r#"<animate attributeName="visibility" values="{values}" keyTimes="{times}" calcMode="discrete" dur="{}s" repeatCount="indefinite"/>"#,
+            seconds * count as f64
+        );
+        svg_cells(&mut out, frame, scale, &f);
+        let _ = writeln!(out, "</g>");
+    });
+
+    out + "</svg>\n"
+}
+
+/// Represents the file format of an exported image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Represents a static SVG document.
+    Svg,
+    /// Represents a binary PPM file.
+    Ppm,
+    /// Represents an uncompressed PNG file.
+    Png,
+}
+
+impl Format {
+    /// Returns the format matching the extension of the given path, if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "svg" => Some(Self::Svg),
+            "ppm" => Some(Self::Ppm),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+
+    /// Returns the file extension of the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Svg => "svg",
+            Self::Ppm => "ppm",
+            Self::Png => "png",
+        }
+    }
+
+    /// Encodes the grid in this format, drawing each cell as a square of the given size.
+    pub fn encode<T, F>(self, grid: &Grid<T>, scale: usize, f: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> Colour,
+    {
+        match self {
+            Self::Svg => svg(grid, scale, f).into_bytes(),
+            Self::Ppm => Image::from_grid(grid, scale, f).to_ppm(),
+            Self::Png => Image::from_grid(grid, scale, f).to_png(),
+        }
+    }
+}
+
+/// Write every frame to a numbered file in the given directory, from `frame_0000.png` onwards.
+///
+/// Returns the path of every file written, in order. Nothing is written if any frame would
+/// be drawn without any pixels, which PNG does not allow.
+pub fn write_frames<T, F>(
+    dir: &Path,
+    frames: &[Grid<T>],
+    format: Format,
+    scale: usize,
+    f: F,
+) -> io::Result<Vec<PathBuf>>
+where
+    F: Fn(&T) -> Colour,
+{
+    // Assert that every frame has at least one pixel, before writing any of them.
+    if let Some(index) = frames
+        .iter()
+        .position(|frame| frame.rows * scale == 0 || frame.cols * scale == 0)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("frame {index} has no pixels"),
+        ));
+    }
+
+    fs::create_dir_all(dir)?;
+
+    frames
+        .iter()
+        .enumerate()
+        .map(|(index, frame)| {
+            let path = dir.join(format!("frame_{index:04}.{}", format.extension()));
+            fs::write(&path, format.encode(frame, scale, &f))?;
+
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<bool> {
+        Grid::try_from([[true, false], [false, true]]).unwrap()
+    }
+
+    fn colour(b: &bool) -> Colour {
+        if *b {
+            Colour::WHITE
+        } else {
+            Colour::BLACK
+        }
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    }
+
+    #[test]
+    fn raster() {
+        let image = Image::from_grid(&grid(), 2, colour);
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(image.pixels[5], Colour::WHITE);
+        assert_eq!(image.pixels[6], Colour::BLACK);
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let png = image.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert!(png.ends_with(&[0xae, 0x42, 0x60, 0x82]));
+    }
+
+    #[test]
+    fn vector() {
+        let document = svg(&grid(), 10, colour);
+        assert_eq!(document.matches("<rect").count(), 4);
+        assert!(
+            document.contains(r##"<rect x="10" y="10" width="10" height="10" fill="#ffffff"/>"##)
+        );
+
+        let frames = [grid(), grid()];
+        let animated = animated_svg(&frames, 10, 0.5, colour);
+        assert_eq!(animated.matches("<animate ").count(), 2);
+        assert!(animated.contains(r#"keyTimes="0;0.5;1""#));
+        assert_eq!(Format::from_path(Path::new("day4.png")), Some(Format::Png));
+    }
+
+    #[test]
+    fn empty_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-2024-frames-{}", std::process::id()));
+        let empty = Grid {
+            rows: 0,
+            cols: 0,
+            items: Vec::new().into(),
+            ..grid()
+        };
+
+        let error = write_frames(&dir, &[grid(), empty], Format::Png, 1, colour).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(error.to_string(), "frame 1 has no pixels");
+
+        let error = write_frames(&dir, &[grid()], Format::Png, 0, colour).unwrap_err();
+        assert_eq!(error.to_string(), "frame 0 has no pixels");
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,232 @@
+use std::rc::Rc;
+
+use crate::common::geometry::{Point, Vector};
+
+pub mod bits;
+pub mod boundary;
+pub mod image;
+pub mod region;
+pub mod render;
+pub mod sparse;
+pub mod text;
+
+pub use bits::BitGrid;
+pub use boundary::Boundary;
+pub use image::{Colour, Format, Image};
+pub use region::{Connectivity, Region, RegionId, Regions};
+pub use render::{Highlight, Style};
+pub use sparse::SparseGrid;
+pub use text::ParseError;
+
+/// ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    /// Represents the non-zero number of rows in the grid.
+    pub rows: usize,
+    /// Represents the non-zero number of columns in the grid.
+    pub cols: usize,
+    /// Represents a shared flattened slice of all items in the grid.
+    pub items: Rc<[T]>,
+    /// Represents the current position of the grid view.
+    pub cursor: Point,
+    /// Represents the behaviour of the grid view when moved past an edge.
+    pub boundary: Boundary<T>,
+    /// Represents whether the text the grid was parsed from ended in a newline.
+    pub trailing_newline: bool,
+}
+
+// Construction method implementations.
+impl<T> Grid<T> {
+    /// ...
+    fn unchecked_from<I>(
+        items: I,
+        rows: usize,
+        cols: usize,
+        cursor: Point,
+        boundary: Boundary<T>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // Flatten the nested iterators into a single stream and collect as `Rc<[T]>`.
+        let items = items.into_iter().collect();
+
+        // Create `Grid` instance under the assumption that the input is valid.
+        Grid {
+            rows,
+            cols,
+            items,
+            cursor,
+            boundary,
+            trailing_newline: false,
+        }
+    }
+
+    /// ...
+    pub fn try_from<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator<Item = T>,
+    {
+        // Collect iterator into nested `Vec`s to ensure proper row and column sizes.
+        let items: Vec<Vec<T>> = items
+            .into_iter()
+            .map(|line| line.into_iter().collect())
+            .collect();
+
+        // Assert that the number of rows is non-zero.
+        let rows = items.first().map(|_| items.len())?;
+        // Assert that the number of cols is non-zero given that the number of rows is non-zero.
+        let cols = (!items[0].is_empty()).then(|| items[0].len())?;
+
+        // Assert that all rows have equal lengths (number of columns).
+        items.iter().all(|line| line.len() == cols).then_some(())?;
+
+        // Construct `Grid` from unsafe interface given all conditions have been validated.
+        Some(Grid::unchecked_from(
+            items.into_iter().flatten(),
+            rows,
+            cols,
+            Point::default(),
+            Boundary::Reject,
+        ))
+    }
+
+    /// Returns the grid with the given behaviour when its cursor is moved past an edge.
+    pub fn with_boundary(self, boundary: Boundary<T>) -> Self {
+        Grid { boundary, ..self }
+    }
+}
+
+// Iteration method implementations.
+impl<T> Grid<T> {
+    /// ...
+    pub fn iter_items(&self) -> impl Iterator<Item = &T> {
+        // Iterate over references to avoid preemptive cloning.
+        self.items.iter()
+    }
+
+    /// ...
+    pub fn iter_cursors(&self) -> impl Iterator<Item = Point> {
+        let rows = 0..self.rows;
+        let cols = 0..self.cols;
+
+        // Calculate the cartesian product of all cursors and iterate in order.
+        rows.flat_map(move |row| cols.clone().map(move |col| Point::from((row, col))))
+    }
+}
+
+// Grid-specific method implementations.
+impl<T> Grid<T> {
+    /// ...
+    pub fn get(&self, point: Point) -> Option<&T> {
+        // Convert cartesian coordinates to absolute index and borrow with bounds checking.
+        self.items.get(point.to_index(self.rows, self.cols)?)
+    }
+
+    /// ...
+    pub fn shift(&self, offset: Vector) -> Option<Grid<T>> {
+        // ...
+        self.focus(self.cursor + offset)
+    }
+
+    /// ...
+    pub fn focus(&self, point: Point) -> Option<Grid<T>> {
+        // Move the point back onto the grid according to the boundary behaviour.
+        let cursor = self.boundary.resolve(point, self.rows, self.cols)?;
+
+        // ...
+        Some(Grid {
+            rows: self.rows,
+            cols: self.cols,
+            items: self.items.clone(),
+            cursor,
+            boundary: self.boundary.clone(),
+            trailing_newline: self.trailing_newline,
+        })
+    }
+}
+
+// Comonad instance implementation.
+impl<T> Grid<T> {
+    /// ...
+    pub fn extract(&self) -> &T {
+        match self.cursor.to_index(self.rows, self.cols) {
+            // Index directly when the cursor is in-bounds.
+            Some(index) => &self.items[index],
+            // Only `Boundary::Fill` can move the cursor out-of-bounds, so this is safe.
+            None => self.boundary.fill().unwrap(),
+        }
+    }
+
+    /// ...
+    pub fn extend<F, U>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(Grid<T>) -> U,
+    {
+        let cursors = self.iter_cursors();
+
+        // Apply the given function to every cursor view of the grid.
+        let items = cursors.map(|cursor| f(self.focus(cursor).unwrap()));
+
+        // Collect from unchecked iterator because we know that dimensions are safe.
+        Grid {
+            trailing_newline: self.trailing_newline,
+            ..Grid::unchecked_from(
+                items,
+                self.rows,
+                self.cols,
+                self.cursor,
+                self.boundary.cast(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::try_from([[1, 2, 3], [4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn boundaries() {
+        let offset = Vector::new(-1, 4);
+
+        assert!(grid().shift(offset).is_none());
+
+        let clamp = grid().with_boundary(Boundary::Clamp);
+        assert_eq!(*clamp.shift(offset).unwrap().extract(), 3);
+
+        let wrap = grid().with_boundary(Boundary::Wrap);
+        assert_eq!(*wrap.shift(offset).unwrap().extract(), 5);
+
+        let fill = grid().with_boundary(Boundary::Fill(Rc::new(0)));
+        assert_eq!(*fill.shift(offset).unwrap().extract(), 0);
+        assert_eq!(
+            *fill
+                .shift(offset)
+                .unwrap()
+                .shift(-offset)
+                .unwrap()
+                .extract(),
+            1
+        );
+    }
+
+    #[test]
+    fn extend() {
+        // Sum each cell with its right neighbour, wrapping around the edge.
+        let wrap = grid().with_boundary(Boundary::Wrap);
+        let sums =
+            wrap.extend(|view| view.extract() + view.shift(Vector::new(0, 1)).unwrap().extract());
+
+        assert_eq!(
+            sums.iter_items().copied().collect::<Vec<_>>(),
+            vec![3, 5, 4, 9, 11, 10]
+        );
+        assert_eq!(sums.boundary, Boundary::Wrap);
+    }
+}
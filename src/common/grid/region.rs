@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::common::{
+    geometry::{Bounds, Direction4, Direction8, Point},
+    search::bfs,
+};
+
+use super::Grid;
+
+/// Represents the index of a region in the order it was first encountered.
+pub type RegionId = usize;
+
+/// Represents which neighbouring cells are considered connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Represents cells connected only through their edges.
+    Four,
+    /// Represents cells connected through their edges or their corners.
+    Eight,
+}
+
+impl Connectivity {
+    /// Iterate over the directions of every connected neighbour.
+    pub fn directions(self) -> impl Iterator<Item = Direction8> {
+        Direction8::iter().filter(move |direction| match self {
+            Self::Four => !direction.is_diagonal(),
+            Self::Eight => true,
+        })
+    }
+}
+
+/// Represents a connected component of equal cells in a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Represents the identifier of the region within its label map.
+    pub id: RegionId,
+    /// Represents every cell of the region, in row-major order.
+    pub cells: Vec<Point>,
+    /// Represents the number of cell edges separating the region from any other cell.
+    pub perimeter: usize,
+    /// Represents the number of corners of the region, which is also its number of sides.
+    pub corners: usize,
+    /// Represents the bounding box of every cell in the region.
+    pub bounds: Bounds,
+}
+
+impl Region {
+    /// Returns the number of cells in the region.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of straight sides of the region, including those of any holes.
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+/// Represents every region of a grid along with the region of each cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    /// Represents the region of every cell in the grid.
+    pub labels: Grid<RegionId>,
+    /// Represents every region, indexed by its identifier.
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Label every connected component of equal cells in the grid.
+    pub fn label<T>(grid: &Grid<T>, connectivity: Connectivity) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut labels: HashMap<Point, RegionId> = HashMap::new();
+        let mut ids = 0..;
+
+        // Flood fill from every cell which has not been labelled yet.
+        let components = grid
+            .iter_cursors()
+            .filter_map(|start| {
+                (!labels.contains_key(&start)).then_some(())?;
+
+                let value = grid.get(start)?;
+                let paths = bfs([start], |&point| {
+                    connectivity
+                        .directions()
+                        .map(move |direction| point + direction.vector())
+                        .filter(|next| grid.get(*next) == Some(value))
+                        .collect::<Vec<_>>()
+                });
+
+                let mut cells = paths.costs.into_keys().collect::<Vec<_>>();
+                cells.sort();
+
+                let id = ids.next()?;
+                cells.iter().for_each(|cell| {
+                    labels.insert(*cell, id);
+                });
+
+                Some((id, cells))
+            })
+            .collect::<Vec<_>>();
+
+        let labels = grid.extend(|view| labels[&view.cursor]);
+
+        let regions = components
+            .into_iter()
+            .map(|(id, cells)| Self::measure(&labels, id, cells))
+            .collect();
+
+        Regions { labels, regions }
+    }
+
+    /// Measure the perimeter, corners and bounds of a labelled region.
+    fn measure(labels: &Grid<RegionId>, id: RegionId, cells: Vec<Point>) -> Region {
+        let inside = |point: Point| labels.get(point) == Some(&id);
+
+        // Count every edge of every cell which does not border the same region.
+        let perimeter = cells
+            .iter()
+            .flat_map(|cell| Direction4::iter().map(move |direction| *cell + direction.vector()))
+            .filter(|next| !inside(*next))
+            .count();
+
+        // Count the corners of every cell, looking at each pair of adjacent edges.
+        let corners = cells
+            .iter()
+            .flat_map(|cell| {
+                Direction4::iter().map(move |direction| {
+                    let (lhs, rhs) = (direction, direction.rotate_cw());
+
+                    let lhs_inside = inside(*cell + lhs.vector());
+                    let rhs_inside = inside(*cell + rhs.vector());
+                    let diagonal_inside = inside(*cell + lhs.vector() + rhs.vector());
+
+                    // Convex corners have neither edge inside, and concave corners have both
+                    // edges inside but not the diagonal between them.
+                    (!lhs_inside && !rhs_inside) || (lhs_inside && rhs_inside && !diagonal_inside)
+                })
+            })
+            .filter(|corner| *corner)
+            .count();
+
+        // Regions always contain at least their starting cell, so this is safe.
+        let bounds = Bounds::from_points(cells.iter().copied()).unwrap();
+
+        Region {
+            id,
+            cells,
+            perimeter,
+            corners,
+            bounds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        input.parse::<Grid<char>>().unwrap()
+    }
+
+    #[test]
+    fn garden() {
+        let regions = Regions::label(&grid("AAAA\nBBCD\nBBCC\nEEEC"), Connectivity::Four);
+
+        let measures = regions
+            .regions
+            .iter()
+            .map(|region| (region.area(), region.perimeter, region.sides()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            measures,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions.labels.get(Point::new(3, 3)), Some(&2));
+    }
+
+    #[test]
+    fn holes() {
+        let regions = Regions::label(
+            &grid("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO"),
+            Connectivity::Four,
+        );
+
+        assert_eq!(regions.regions.len(), 5);
+        assert_eq!(regions.regions[0].area(), 21);
+        assert_eq!(regions.regions[0].perimeter, 36);
+        assert_eq!(regions.regions[0].sides(), 20);
+    }
+
+    #[test]
+    fn diagonals() {
+        let input = grid("X.\n.X");
+
+        assert_eq!(Regions::label(&input, Connectivity::Four).regions.len(), 4);
+
+        let regions = Regions::label(&input, Connectivity::Eight);
+        assert_eq!(regions.regions.len(), 2);
+        assert_eq!(regions.regions[0].bounds.max, Point::new(1, 1));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use crate::common::geometry::Point;
+
+use super::Grid;
+
+/// Represents the colour used to highlight a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Highlight {
+    /// Represents red text.
+    Red,
+    /// Represents green text.
+    Green,
+    /// Represents yellow text.
+    Yellow,
+    /// Represents blue text.
+    Blue,
+    /// Represents magenta text.
+    Magenta,
+    /// Represents cyan text.
+    Cyan,
+}
+
+impl Highlight {
+    /// Returns the ANSI foreground colour code of the highlight.
+    fn code(self) -> u8 {
+        match self {
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+        }
+    }
+}
+
+/// Represents how highlighted cells are distinguished from the rest of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Represents bold coloured highlights, with every other cell dimmed.
+    Ansi,
+    /// Represents highlights printed as is, with every other cell replaced by a `.`.
+    Plain,
+}
+
+impl Style {
+    /// Returns `Ansi` if stdout is a terminal and `NO_COLOR` is unset, otherwise `Plain`.
+    pub fn detect() -> Self {
+        match io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+            true => Self::Ansi,
+            false => Self::Plain,
+        }
+    }
+}
+
+/// Render the grid one row per line, marking every cell with a highlight in the other grid.
+///
+/// Cells without a matching position in the highlights are left unmarked.
+pub fn render(grid: &Grid<char>, highlights: &Grid<Option<Highlight>>, style: Style) -> String {
+    let cell = |c: char, highlight: Option<Highlight>| match (style, highlight) {
+        (Style::Ansi, Some(highlight)) => format!("\x1b[1;{}m{c}\x1b[0m", highlight.code()),
+        (Style::Ansi, None) => format!("\x1b[2m{c}\x1b[0m"),
+        (Style::Plain, Some(_)) => c.to_string(),
+        (Style::Plain, None) => ".".to_string(),
+    };
+
+    grid.items
+        .chunks(grid.cols)
+        .enumerate()
+        // Render every cell with the highlight at the same position, if any.
+        .map(|(row, items)| {
+            items
+                .iter()
+                .enumerate()
+                .map(|(col, c)| {
+                    let point = Point::from((row, col));
+                    cell(*c, highlights.get(point).copied().flatten())
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles() {
+        let grid = "XMAS\nSAMX".parse::<Grid<char>>().unwrap();
+        let highlights = grid.extend(|view| (view.cursor.row == 0).then_some(Highlight::Green));
+
+        assert_eq!(render(&grid, &highlights, Style::Plain), "XMAS\n....");
+
+        let ansi = render(&grid, &highlights, Style::Ansi);
+        assert!(ansi.starts_with("\x1b[1;32mX\x1b[0m"));
+        assert!(ansi.ends_with("\x1b[2mX\x1b[0m"));
+    }
+}
@@ -0,0 +1,295 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::common::geometry::{Bounds, Point, Vector};
+
+/// Unbounded grid backed by a map of occupied cells, with every other cell holding a default.
+///
+/// Like `Grid`, it is viewed through a cursor, and views share their cells so that
+/// shifting and focusing are cheap. Unlike `Grid`, the cursor may take any signed
+/// position, so neither `shift` nor `focus` can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    /// Represents a shared map of every occupied cell in the grid.
+    pub items: Rc<HashMap<Point, T>>,
+    /// Represents the shared value held by every unoccupied cell.
+    pub default: Rc<T>,
+    /// Represents the bounding box of every occupied cell, if any.
+    pub bounds: Option<Bounds>,
+    /// Represents the current position of the grid view.
+    pub cursor: Point,
+}
+
+// Construction method implementations.
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid where every cell holds the given default.
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            items: Rc::new(HashMap::new()),
+            default: Rc::new(default),
+            bounds: None,
+            cursor: Point::default(),
+        }
+    }
+
+    /// Creates a grid from the given occupied cells, with every other cell holding the default.
+    pub fn from_items<I>(default: T, items: I) -> Self
+    where
+        I: IntoIterator<Item = (Point, T)>,
+    {
+        let items = items.into_iter().collect::<HashMap<_, _>>();
+
+        // Compute the bounding box once all cells have been collected.
+        let bounds = Bounds::from_points(items.keys().copied());
+
+        SparseGrid {
+            items: Rc::new(items),
+            default: Rc::new(default),
+            bounds,
+            cursor: Point::default(),
+        }
+    }
+
+    /// Creates a grid from lines of characters, occupying only the cells accepted by `f`.
+    ///
+    /// The first character of the first line is placed at the origin.
+    pub fn parse<F>(input: &str, default: T, f: F) -> Self
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let f = &f;
+
+        let items = input
+            .lines()
+            .enumerate()
+            // Map each character to its position, keeping only the occupied ones.
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter_map(move |(col, c)| f(c).map(|item| (Point::from((row, col)), item)))
+                    .collect::<Vec<_>>()
+            });
+
+        Self::from_items(default, items)
+    }
+}
+
+// Mutation method implementations.
+impl<T: Clone> SparseGrid<T> {
+    /// Occupies the cell at the given point, returning its previous occupant.
+    ///
+    /// Cells shared with other views are copied before being modified.
+    pub fn insert(&mut self, point: Point, item: T) -> Option<T> {
+        // Grow the bounding box to include the new point.
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => Bounds::from_point(point),
+        });
+
+        Rc::make_mut(&mut self.items).insert(point, item)
+    }
+
+    /// Resets the cell at the given point to the default, returning its previous occupant.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let item = Rc::make_mut(&mut self.items).remove(&point)?;
+
+        // Only recompute the bounding box if the point was on its edge.
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            [bounds.min.row, bounds.max.row].contains(&point.row)
+                || [bounds.min.col, bounds.max.col].contains(&point.col)
+        });
+
+        if on_edge {
+            self.bounds = Bounds::from_points(self.items.keys().copied());
+        }
+
+        Some(item)
+    }
+}
+
+// Iteration method implementations.
+impl<T> SparseGrid<T> {
+    /// Iterate over every occupied cell, in no particular order.
+    pub fn iter_items(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.items.iter().map(|(point, item)| (*point, item))
+    }
+
+    /// Iterate over every position within the bounding box of the occupied cells.
+    pub fn iter_cursors(&self) -> impl Iterator<Item = Point> {
+        self.bounds
+            .into_iter()
+            .flat_map(|bounds| bounds.iter_points())
+    }
+
+    /// Returns the number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether no cell is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+// Grid-specific method implementations.
+impl<T> SparseGrid<T> {
+    /// Returns the value of the cell at the given point, occupied or not.
+    pub fn get(&self, point: Point) -> &T {
+        self.items.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Returns whether the cell at the given point is occupied.
+    pub fn contains(&self, point: Point) -> bool {
+        self.items.contains_key(&point)
+    }
+
+    /// Returns a view of the grid with the cursor moved by the given offset.
+    pub fn shift(&self, offset: Vector) -> SparseGrid<T> {
+        self.focus(self.cursor + offset)
+    }
+
+    /// Returns a view of the grid with the cursor at the given point.
+    pub fn focus(&self, point: Point) -> SparseGrid<T> {
+        SparseGrid {
+            items: self.items.clone(),
+            default: self.default.clone(),
+            bounds: self.bounds,
+            cursor: point,
+        }
+    }
+
+    /// Render every cell within the bounding box of the occupied cells, one line per row.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+
+        (bounds.min.row..=bounds.max.row)
+            // Render each row of the bounding box as a line of characters.
+            .map(|row| {
+                (bounds.min.col..=bounds.max.col)
+                    .map(|col| f(self.get(Point::new(row, col))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// Comonad instance implementation.
+impl<T> SparseGrid<T> {
+    /// Returns a view sharing the default of this grid, but with no occupied cells.
+    fn empty(&self) -> SparseGrid<T> {
+        SparseGrid {
+            items: Rc::new(HashMap::new()),
+            default: self.default.clone(),
+            bounds: None,
+            cursor: self.cursor,
+        }
+    }
+
+    /// Returns the value of the cell under the cursor.
+    pub fn extract(&self) -> &T {
+        self.get(self.cursor)
+    }
+
+    /// Apply the given function to a view focused on every occupied cell.
+    ///
+    /// The default of the resulting grid is the function applied to a view of an
+    /// empty grid, which is the value it takes far away from every occupied cell.
+    pub fn extend<F, U>(&self, f: F) -> SparseGrid<U>
+    where
+        F: Fn(SparseGrid<T>) -> U,
+    {
+        let items = self
+            .items
+            .keys()
+            .map(|point| (*point, f(self.focus(*point))));
+
+        // Evaluate the default on a view with no occupied cells at all.
+        let default = f(self.empty());
+
+        SparseGrid {
+            cursor: self.cursor,
+            ..SparseGrid::from_items(default, items)
+        }
+    }
+
+    /// Apply the given function to a view focused on every cell within the bounding box,
+    /// grown by the given margin, whether occupied or not.
+    ///
+    /// This allows occupied regions to grow, such as in cellular automata.
+    pub fn extend_bounded<F, U>(&self, margin: isize, f: F) -> SparseGrid<U>
+    where
+        F: Fn(SparseGrid<T>) -> U,
+    {
+        let points = self.bounds.map(|bounds| bounds.grow(margin));
+        let items = points
+            .into_iter()
+            .flat_map(|bounds| bounds.iter_points())
+            .map(|point| (point, f(self.focus(point))))
+            .collect::<Vec<_>>();
+
+        // Evaluate the default on a view with no occupied cells at all.
+        let default = f(self.empty());
+
+        SparseGrid {
+            cursor: self.cursor,
+            ..SparseGrid::from_items(default, items)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::geometry::Direction8;
+
+    #[test]
+    fn sparse() {
+        let mut grid = SparseGrid::parse("#..\n..#", false, |c| (c == '#').then_some(true));
+        grid.insert(Point::new(-2, -1), true);
+
+        assert_eq!(
+            grid.bounds.map(|bounds| bounds.min),
+            Some(Point::new(-2, -1))
+        );
+        assert_eq!(
+            grid.render(|&b| if b { '#' } else { '.' }),
+            "#...\n....\n.#..\n...#"
+        );
+        assert!(*grid.focus(Point::new(1, 2)).extract());
+        assert!(!*grid.shift(Vector::new(-100, 100)).extract());
+
+        grid.remove(Point::new(-2, -1));
+        assert_eq!(grid.bounds.map(|bounds| bounds.min), Some(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn life() {
+        // A blinker oscillates between a horizontal and a vertical line.
+        let grid = SparseGrid::parse("###", false, |c| (c == '#').then_some(true));
+
+        let step = |grid: SparseGrid<bool>| {
+            let neighbours = Direction8::iter()
+                .filter(|direction| *grid.shift(direction.vector()).extract())
+                .count();
+
+            neighbours == 3 || (neighbours == 2 && *grid.extract())
+        };
+
+        let next = grid.extend_bounded(1, step);
+        let alive = SparseGrid::from_items(
+            false,
+            next.iter_items()
+                .filter(|(_, b)| **b)
+                .map(|(p, _)| (p, true)),
+        );
+
+        assert_eq!(alive.render(|&b| if b { '#' } else { '.' }), "#\n#\n#");
+        assert!(!next.default.as_ref());
+    }
+}
@@ -0,0 +1,166 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::common::geometry::Point;
+
+use super::{Boundary, Grid};
+
+/// Represents the reason a grid of characters could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Represents an input without any rows, or whose first row is empty.
+    Empty,
+    /// Represents a row whose width differs from the width of the first row.
+    Ragged {
+        /// Represents the 1-based line number of the first row with a different width.
+        line: usize,
+        /// Represents the width of the first row.
+        expected: usize,
+        /// Represents the width of the offending row.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid has no rows or columns"),
+            Self::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} columns, but the grid is {expected} columns wide"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        // Assert that there is at least one row and one column.
+        let cols = lines
+            .first()
+            .map(|line| line.chars().count())
+            .filter(|cols| *cols > 0)
+            .ok_or(ParseError::Empty)?;
+
+        // Report the first row whose width differs from the first.
+        let ragged = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .enumerate()
+            .find(|(_, found)| *found != cols);
+
+        if let Some((index, found)) = ragged {
+            return Err(ParseError::Ragged {
+                line: index + 1,
+                expected: cols,
+                found,
+            });
+        }
+
+        // Remember a trailing newline, so that printing the grid gives back the input.
+        Ok(Grid {
+            trailing_newline: input.ends_with('\n'),
+            ..Grid::unchecked_from(
+                lines.iter().flat_map(|line| line.chars()),
+                lines.len(),
+                cols,
+                Point::default(),
+                Boundary::Reject,
+            )
+        })
+    }
+}
+
+/// Prints the grid one row per line, so that parsing the output gives back the same grid.
+///
+/// The last row is only followed by a newline if the grid was parsed from input ending in
+/// one, so printing a parsed grid gives back its input.
+///
+/// The alternate flag (`{:#}`) adds a line with a caret under the cursor, if it is on the grid.
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cursor = self
+            .cursor
+            .to_cursor()
+            .filter(|_| f.alternate() && self.get(self.cursor).is_some());
+
+        self.items
+            .chunks(self.cols)
+            .enumerate()
+            .try_for_each(|(row, items)| {
+                if row > 0 {
+                    writeln!(f)?;
+                }
+                items.iter().try_for_each(|c| write!(f, "{c}"))?;
+
+                // Point at the cursor directly below its row.
+                match cursor {
+                    Some((cursor_row, col)) if cursor_row == row => {
+                        write!(f, "\n{:col$}^", "")
+                    }
+                    _ => Ok(()),
+                }
+            })?;
+
+        match self.trailing_newline {
+            true => writeln!(f),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "MMMS\nMSAM\nAMXS";
+        let grid = input.parse::<Grid<char>>().unwrap();
+
+        assert_eq!((grid.rows, grid.cols), (3, 4));
+        assert_eq!(grid.to_string(), input);
+
+        let focused = grid.focus(Point::new(1, 2)).unwrap();
+        assert_eq!(format!("{focused:#}"), "MMMS\nMSAM\n  ^\nAMXS");
+
+        // A trailing newline does not add a row, but is printed back.
+        let input = format!("{input}\n");
+        let trailing = input.parse::<Grid<char>>().unwrap();
+        assert_eq!((trailing.rows, trailing.cols), (3, 4));
+        assert_eq!(trailing.items, grid.items);
+        assert_eq!(trailing.to_string(), input);
+        assert_eq!(trailing.focus(Point::new(2, 0)).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Grid<char>>(), Err(ParseError::Empty));
+        assert_eq!(
+            "ab\nab\nabc\na".parse::<Grid<char>>(),
+            Err(ParseError::Ragged {
+                line: 3,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            ParseError::Ragged {
+                line: 3,
+                expected: 2,
+                found: 3
+            }
+            .to_string(),
+            "line 3 has 3 columns, but the grid is 2 columns wide"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod search;
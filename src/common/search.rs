@@ -0,0 +1,359 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::common::{
+    geometry::{Direction4, Point},
+    grid::Grid,
+};
+
+/// Represents the shortest paths from a set of starting states to every reachable state.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    /// Represents the cost of the shortest path to every reachable state.
+    pub costs: HashMap<S, u64>,
+    /// Represents every predecessor of each state on some shortest path to it.
+    pub parents: HashMap<S, Vec<S>>,
+}
+
+impl<S> Paths<S>
+where
+    S: Hash + Eq + Clone,
+{
+    /// Creates paths where only the given starting states are reachable, at no cost.
+    fn from_starts<I>(starts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Paths {
+            costs: starts.into_iter().map(|start| (start, 0)).collect(),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Record an edge from `state` to `next` with the given total cost.
+    ///
+    /// Returns whether the edge improved the cost of reaching `next`.
+    fn relax(&mut self, state: &S, next: S, cost: u64) -> bool {
+        match self.costs.get(&next).copied() {
+            // A strictly cheaper path replaces every previous predecessor.
+            Some(known) if cost < known => {
+                self.costs.insert(next.clone(), cost);
+                self.parents.insert(next, vec![state.clone()]);
+                true
+            }
+            // Starting states are the only ones reached without a predecessor, and never get one,
+            // so zero-cost edges back to them cannot make `path_to` loop.
+            Some(_) if !self.parents.contains_key(&next) => false,
+            // An equally cheap path adds another predecessor.
+            Some(known) if cost == known => {
+                self.parents.entry(next).or_default().push(state.clone());
+                false
+            }
+            // A more expensive path is discarded.
+            Some(_) => false,
+            // The state has never been reached before.
+            None => {
+                self.costs.insert(next.clone(), cost);
+                self.parents.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Returns the cost of the shortest path to the given state, if it is reachable.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.costs.get(state).copied()
+    }
+
+    /// Returns one shortest path to the given state, from its starting state.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.cost(goal)?;
+
+        // Follow the first predecessor of every state until a starting state is reached.
+        let mut path = std::iter::successors(Some(goal.clone()), |state| {
+            self.parents
+                .get(state)
+                .and_then(|parents| parents.first().cloned())
+        })
+        .collect::<Vec<_>>();
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns every state lying on any shortest path to any of the given goals.
+    pub fn on_shortest<I>(&self, goals: I) -> HashSet<S>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut seen = HashSet::new();
+        let mut stack = goals
+            .into_iter()
+            .filter(|goal| self.costs.contains_key(goal))
+            .collect::<Vec<_>>();
+
+        // Walk backwards through every predecessor, visiting each state once.
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.parents.get(&state).into_iter().flatten().cloned());
+            }
+        }
+
+        seen
+    }
+}
+
+/// Find the shortest paths from the starting states, where every edge costs 1.
+pub fn bfs<S, N, I>(starts: impl IntoIterator<Item = S>, neighbours: N) -> Paths<S>
+where
+    S: Hash + Eq + Clone,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::from_starts(starts);
+    let mut queue = paths.costs.keys().cloned().collect::<VecDeque<_>>();
+
+    // Visit states in order of their distance, since every edge has the same cost.
+    while let Some(state) = queue.pop_front() {
+        let cost = paths.costs[&state] + 1;
+
+        neighbours(&state).into_iter().for_each(|next| {
+            if paths.relax(&state, next.clone(), cost) {
+                queue.push_back(next);
+            }
+        });
+    }
+
+    paths
+}
+
+/// Find the shortest paths from the starting states, where each edge has the given cost.
+pub fn dijkstra<S, N, I>(starts: impl IntoIterator<Item = S>, neighbours: N) -> Paths<S>
+where
+    S: Hash + Ord + Clone,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut paths = Paths::from_starts(starts);
+    let mut heap = paths
+        .costs
+        .keys()
+        .map(|state| Reverse((0, state.clone())))
+        .collect::<BinaryHeap<_>>();
+
+    // Visit states in order of their cost, skipping outdated entries in the heap.
+    while let Some(Reverse((cost, state))) = heap.pop() {
+        if cost > paths.costs[&state] {
+            continue;
+        }
+
+        neighbours(&state).into_iter().for_each(|(next, weight)| {
+            if paths.relax(&state, next.clone(), cost + weight) {
+                heap.push(Reverse((cost + weight, next)));
+            }
+        });
+    }
+
+    paths
+}
+
+/// Find one shortest path from the starting states to any goal, guided by a heuristic.
+///
+/// The heuristic must never overestimate the remaining cost to the nearest goal,
+/// otherwise the path found may not be the shortest.
+pub fn astar<S, N, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: N,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(u64, Vec<S>)>
+where
+    S: Hash + Ord + Clone,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: Fn(&S) -> u64,
+    G: Fn(&S) -> bool,
+{
+    let mut paths = Paths::from_starts(starts);
+    let mut heap = paths
+        .costs
+        .keys()
+        .map(|state| Reverse((heuristic(state), 0, state.clone())))
+        .collect::<BinaryHeap<_>>();
+
+    // Visit states in order of their estimated total cost, stopping at the first goal.
+    while let Some(Reverse((_, cost, state))) = heap.pop() {
+        if cost > paths.costs[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some((cost, paths.path_to(&state)?));
+        }
+
+        neighbours(&state).into_iter().for_each(|(next, weight)| {
+            if paths.relax(&state, next.clone(), cost + weight) {
+                heap.push(Reverse((
+                    cost + weight + heuristic(&next),
+                    cost + weight,
+                    next,
+                )));
+            }
+        });
+    }
+
+    None
+}
+
+/// Iterate over the orthogonal neighbours of a point which lie on the grid.
+///
+/// Neighbours are found by shifting a view of the grid, so its boundary behaviour applies.
+pub fn neighbours4<T>(grid: &Grid<T>, point: Point) -> impl Iterator<Item = Point> + '_ {
+    let view = grid.focus(point);
+
+    Direction4::iter()
+        // Shift the view in each direction, keeping only cursors which remain on the grid.
+        .filter_map(move |direction| view.as_ref()?.shift(direction.vector()))
+        .map(|view| view.cursor)
+        .filter(|cursor| grid.get(*cursor).is_some())
+}
+
+/// Project the costs of every state onto the grid, keeping the cheapest state for each cell.
+pub fn cost_map<T, S, F>(grid: &Grid<T>, paths: &Paths<S>, project: F) -> Grid<Option<u64>>
+where
+    F: Fn(&S) -> Point,
+{
+    let costs = paths.costs.iter().fold(
+        HashMap::new(),
+        |mut costs: HashMap<Point, u64>, (state, &cost)| {
+            let entry = costs.entry(project(state)).or_insert(cost);
+            *entry = cost.min(*entry);
+            costs
+        },
+    );
+
+    grid.extend(|view| costs.get(&view.cursor).copied())
+}
+
+/// Find the distance from the start to every cell reachable through cells accepted by `passable`.
+pub fn distance_map<T, F>(grid: &Grid<T>, start: Point, passable: F) -> Grid<Option<u64>>
+where
+    F: Fn(&T) -> bool,
+{
+    let paths = bfs([start], |&point| {
+        neighbours4(grid, point)
+            .filter(|next| grid.get(*next).is_some_and(&passable))
+            .collect::<Vec<_>>()
+    });
+
+    cost_map(grid, &paths, |&point| point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "#######\n#S...##\n#.#.#.#\n#...E.#\n#######";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::try_from(MAZE.lines().map(|line| line.chars())).unwrap();
+
+        let find = |c| {
+            grid.iter_cursors()
+                .find(|point| grid.get(*point) == Some(&c))
+        };
+        let (start, end) = (find('S').unwrap(), find('E').unwrap());
+
+        (grid, start, end)
+    }
+
+    #[test]
+    fn distances() {
+        let (grid, start, end) = maze();
+        let distances = distance_map(&grid, start, |c| *c != '#');
+
+        assert_eq!(distances.get(end), Some(&Some(5)));
+        assert_eq!(distances.get(Point::new(0, 0)), Some(&None));
+        assert_eq!(distances.iter_items().flatten().max(), Some(&7));
+    }
+
+    #[test]
+    fn turns() {
+        let (grid, start, end) = maze();
+
+        // Moving forward costs 1, and turning in place costs 1000.
+        let neighbours = |&(point, direction): &(Point, Direction4)| {
+            let forward = grid
+                .get(point + direction.vector())
+                .filter(|c| **c != '#')
+                .map(|_| ((point + direction.vector(), direction), 1));
+            let turns = [direction.rotate_cw(), direction.rotate_ccw()]
+                .map(|direction| ((point, direction), 1000));
+
+            forward.into_iter().chain(turns).collect::<Vec<_>>()
+        };
+
+        let paths = dijkstra([(start, Direction4::Right)], neighbours);
+        let goals = Direction4::ALL.map(|direction| (end, direction));
+        let best = goals.iter().filter_map(|goal| paths.cost(goal)).min();
+
+        assert_eq!(best, Some(2005));
+
+        // Both routes around the middle wall take two turns.
+        let cheapest = goals.into_iter().filter(|goal| paths.cost(goal) == best);
+        let tiles = paths
+            .on_shortest(cheapest)
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect::<HashSet<_>>();
+        assert_eq!(tiles.len(), 9);
+
+        let map = cost_map(&grid, &paths, |(point, _)| *point);
+        assert_eq!(map.get(end), Some(&best));
+    }
+
+    #[test]
+    fn zero_costs() {
+        // Both states can reach each other for free, and only the first leads onwards.
+        let neighbours = |state: &u32| match state {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0), (3, 4)],
+            2 => vec![(1, 0)],
+            _ => vec![],
+        };
+
+        let paths = dijkstra([0], neighbours);
+
+        assert_eq!(paths.parents.get(&0), None);
+        assert_eq!(paths.path_to(&0), Some(vec![0]));
+        assert_eq!(paths.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn heuristic() {
+        let (grid, start, end) = maze();
+
+        let neighbours = |point: &Point| {
+            neighbours4(&grid, *point)
+                .filter(|next| grid.get(*next) != Some(&'#'))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let (cost, path) = astar(
+            [start],
+            neighbours,
+            |point| point.manhattan(end) as u64,
+            |point| *point == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (start, end));
+    }
+}
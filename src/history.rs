@@ -0,0 +1,325 @@
+use std::{fmt, fs, io, path::Path};
+
+use crate::client::Verdict;
+
+/// Represents a single submitted answer and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    /// Represents the time of the submission, in seconds since the Unix epoch.
+    pub time: u64,
+    /// Represents the day of the puzzle.
+    pub day: u32,
+    /// Represents the part of the puzzle.
+    pub part: u32,
+    /// Represents the submitted answer.
+    pub answer: String,
+    /// Represents the verdict given to the answer.
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time, self.day, self.part, self.answer, self.verdict
+        )
+    }
+}
+
+/// Represents the reason an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// Represents a part which was already solved, with the given answer if it is known.
+    Solved { answer: Option<String> },
+    /// Represents an answer which was already judged to be wrong.
+    KnownWrong,
+    /// Represents an answer at or above a guess which was judged too high.
+    AboveBound { bound: i64 },
+    /// Represents an answer at or below a guess which was judged too low.
+    BelowBound { bound: i64 },
+    /// Represents a submission made before every rate limit on the part has run out.
+    RateLimited { remaining: u64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved {
+                answer: Some(answer),
+            } => write!(f, "already solved with {answer}"),
+            Self::Solved { answer: None } => write!(f, "already solved"),
+            Self::KnownWrong => write!(f, "answer was already judged to be wrong"),
+            Self::AboveBound { bound } => write!(f, "answer must be less than {bound}"),
+            Self::BelowBound { bound } => write!(f, "answer must be greater than {bound}"),
+            Self::RateLimited { remaining } => write!(f, "wait {remaining}s before submitting"),
+        }
+    }
+}
+
+impl std::error::Error for Refusal {}
+
+/// Record of every submitted answer, stored as one tab-separated line per guess.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    /// Represents every guess, in the order they were submitted.
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Parse a history from its text form, skipping blank lines.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let guesses = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            // Split each line into its five fields, reporting the line of the first failure.
+            .map(|(index, line)| {
+                let fields = line.split('\t').collect::<Vec<_>>();
+                let invalid = || format!("line {}: invalid guess", index + 1);
+
+                let [time, day, part, answer, verdict] = fields[..] else {
+                    return Err(invalid());
+                };
+
+                Ok(Guess {
+                    time: time.parse().map_err(|_| invalid())?,
+                    day: day.parse().map_err(|_| invalid())?,
+                    part: part.parse().map_err(|_| invalid())?,
+                    answer: answer.to_string(),
+                    verdict: verdict.parse().map_err(|_| invalid())?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(History { guesses })
+    }
+
+    /// Load the history from the given path, which is empty if the file does not exist.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    /// Write the history to the given path, creating its parent directories.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_string())
+    }
+
+    /// Record a guess at the end of the history.
+    pub fn record(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+
+    /// Returns the tightest bounds on the answer to the given part, as `(lower, upper)`.
+    ///
+    /// The answer is greater than the highest guess which was too low, and less than the
+    /// lowest guess which was too high.
+    pub fn bounds(&self, day: u32, part: u32) -> (Option<i64>, Option<i64>) {
+        self.guesses
+            .iter()
+            .filter(|guess| (guess.day, guess.part) == (day, part))
+            // Only numeric guesses bound the answer.
+            .filter_map(|guess| Some((guess.answer.parse::<i64>().ok()?, guess.verdict)))
+            .fold(
+                (None, None),
+                |(low, high), (answer, verdict)| match verdict {
+                    Verdict::TooLow => (low.max(Some(answer)), high),
+                    Verdict::TooHigh => {
+                        (low, Some(high.map_or(answer, |high: i64| high.min(answer))))
+                    }
+                    _ => (low, high),
+                },
+            )
+    }
+
+    /// Check whether the answer to the given part is worth submitting at the given time.
+    pub fn check(&self, day: u32, part: u32, answer: &str, now: u64) -> Result<(), Refusal> {
+        let mut guesses = self
+            .guesses
+            .iter()
+            .filter(|guess| (guess.day, guess.part) == (day, part));
+
+        // Wait for the longest rate limit on the part, whichever guess it was given to.
+        let remaining = guesses
+            .clone()
+            .filter_map(|guess| match guess.verdict {
+                Verdict::RateLimited { wait } => (guess.time + wait.as_secs()).checked_sub(now),
+                _ => None,
+            })
+            .max()
+            .filter(|remaining| *remaining > 0);
+        if let Some(remaining) = remaining {
+            return Err(Refusal::RateLimited { remaining });
+        }
+
+        // A correct answer is known, whereas a part found to be solved may have any answer.
+        if let Some(guess) = guesses
+            .clone()
+            .find(|guess| guess.verdict == Verdict::Correct)
+        {
+            return Err(Refusal::Solved {
+                answer: Some(guess.answer.clone()),
+            });
+        }
+        if guesses
+            .clone()
+            .any(|guess| guess.verdict == Verdict::AlreadySolved)
+        {
+            return Err(Refusal::Solved { answer: None });
+        }
+
+        if guesses.any(|guess| {
+            guess.answer == answer
+                && matches!(
+                    guess.verdict,
+                    Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+                )
+        }) {
+            return Err(Refusal::KnownWrong);
+        }
+
+        let Ok(answer) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+
+        match self.bounds(day, part) {
+            (Some(bound), _) if answer <= bound => Err(Refusal::BelowBound { bound }),
+            (_, Some(bound)) if answer >= bound => Err(Refusal::AboveBound { bound }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.guesses
+            .iter()
+            .try_for_each(|guess| writeln!(f, "{guess}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn guess(time: u64, part: u32, answer: &str, verdict: Verdict) -> Guess {
+        Guess {
+            time,
+            day: 5,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let mut history = History::default();
+        history.record(guess(0, 2, "5000", Verdict::TooLow));
+        history.record(guess(100, 2, "7000", Verdict::TooHigh));
+        history.record(guess(200, 2, "6500", Verdict::TooHigh));
+        history.record(guess(300, 2, "6000", Verdict::Wrong));
+
+        assert_eq!(history.bounds(5, 2), (Some(5000), Some(6500)));
+        assert_eq!(history.bounds(5, 1), (None, None));
+
+        assert_eq!(
+            history.check(5, 2, "4000", 400),
+            Err(Refusal::BelowBound { bound: 5000 })
+        );
+        assert_eq!(
+            history.check(5, 2, "6600", 400),
+            Err(Refusal::AboveBound { bound: 6500 })
+        );
+        assert_eq!(history.check(5, 2, "6000", 400), Err(Refusal::KnownWrong));
+        assert_eq!(history.check(5, 2, "6191", 400), Ok(()));
+        assert_eq!(history.check(5, 1, "4000", 400), Ok(()));
+
+        history.record(guess(500, 2, "6191", Verdict::Correct));
+        assert_eq!(
+            history.check(5, 2, "6191", 600),
+            Err(Refusal::Solved {
+                answer: Some("6191".to_string())
+            })
+        );
+
+        // A part found to be solved is refused without knowing its answer.
+        history.record(guess(700, 1, "5000", Verdict::AlreadySolved));
+        assert_eq!(
+            history.check(5, 1, "5275", 800),
+            Err(Refusal::Solved { answer: None })
+        );
+    }
+
+    #[test]
+    fn rate_limits() {
+        let mut history = History::default();
+        history.record(guess(
+            1000,
+            1,
+            "5275",
+            Verdict::RateLimited {
+                wait: Duration::from_secs(30),
+            },
+        ));
+
+        assert_eq!(
+            history.check(5, 1, "5275", 1010),
+            Err(Refusal::RateLimited { remaining: 20 })
+        );
+        assert_eq!(history.check(5, 1, "5275", 1030), Ok(()));
+
+        // A shorter rate limit given later does not lift a longer one given before it.
+        history.record(guess(
+            1040,
+            1,
+            "5275",
+            Verdict::RateLimited {
+                wait: Duration::from_secs(300),
+            },
+        ));
+        history.record(guess(
+            1050,
+            1,
+            "5275",
+            Verdict::RateLimited {
+                wait: Duration::from_secs(10),
+            },
+        ));
+        assert_eq!(
+            history.check(5, 1, "5275", 1100),
+            Err(Refusal::RateLimited { remaining: 240 })
+        );
+
+        // Rate limits on other parts do not apply.
+        assert_eq!(history.check(5, 2, "6191", 1100), Ok(()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut history = History::default();
+        history.record(guess(1733400000, 1, "5275", Verdict::Correct));
+        history.record(guess(1733400060, 2, "9000", Verdict::TooHigh));
+
+        let text = history.to_string();
+        assert_eq!(
+            text,
+            "1733400000\t5\t1\t5275\tcorrect\n1733400060\t5\t2\t9000\ttoo-high\n"
+        );
+        assert_eq!(History::parse(&text), Ok(history));
+        assert_eq!(
+            History::parse("1\t5\t1\t5275"),
+            Err("line 1: invalid guess".to_string())
+        );
+    }
+}
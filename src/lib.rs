@@ -3,10 +3,15 @@ extern crate aoc_runner;
 #[macro_use]
 extern crate aoc_runner_derive;
 
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
+pub mod cli;
+pub mod client;
+pub mod common;
+pub mod history;
+pub mod puzzle;
+pub mod runner;
+pub mod scaffold;
 
+pub mod year_2024;
+
+// `cargo aoc` only supports a single year per crate, so only 2024 is registered with it.
 aoc_lib! { year = 2024 }
@@ -1,7 +1,15 @@
 extern crate aoc_2024;
-extern crate aoc_runner;
-extern crate aoc_runner_derive;
 
-use aoc_runner_derive::aoc_main;
+use std::{env, process};
 
-aoc_main! { lib = aoc_2024 }
+use aoc_2024::cli::Args;
+
+fn main() {
+    // Parse the command line, skipping the program name, and execute the command.
+    let result = Args::parse(env::args().skip(1)).and_then(Args::execute);
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
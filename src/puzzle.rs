@@ -0,0 +1,322 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Represents the name of the manifest of expected answers within a samples directory.
+pub const MANIFEST: &str = "expected.tsv";
+
+/// Decode the HTML entities used by puzzle descriptions, and drop any inline tags.
+pub fn decode(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    // Copy text up to each tag or entity, then skip the tag or decode the entity.
+    while let Some(start) = rest.find(['<', '&']) {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with('<') {
+            rest = rest.split_once('>').map_or("", |(_, rest)| rest);
+            continue;
+        }
+
+        let decoded = [
+            ("&lt;", '<'),
+            ("&gt;", '>'),
+            ("&amp;", '&'),
+            ("&quot;", '"'),
+            ("&#39;", '\''),
+        ]
+        .into_iter()
+        .find(|(entity, _)| rest.starts_with(entity));
+
+        match decoded {
+            Some((entity, char)) => {
+                text.push(char);
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
+
+/// Returns the text of every `<pre><code>` block of a puzzle description, in order.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        // The text before the first block is not part of any block.
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(block, _)| decode(block))
+        .collect()
+}
+
+/// Returns the text of every `<code><em>` span of a puzzle description, in order.
+///
+/// Puzzle descriptions emphasise the answer to each example this way.
+pub fn emphasised(html: &str) -> Vec<String> {
+    html.split("<code><em>")
+        // The text before the first span is not part of any span.
+        .skip(1)
+        .filter_map(|span| span.split_once("</em></code>"))
+        .map(|(span, _)| decode(span))
+        .collect()
+}
+
+/// Represents the example given by a single part of a puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    /// Represents the sample input of the example, if any.
+    pub sample: Option<String>,
+    /// Represents the answer to the example, if any.
+    pub answer: Option<String>,
+}
+
+/// Examples of every part of a saved puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Description {
+    /// Represents the example of each part, in order.
+    pub parts: Vec<Example>,
+}
+
+impl Description {
+    /// Parse the examples from a saved puzzle page, with one `<article>` per unlocked part.
+    ///
+    /// The sample of each part is its longest code block, and its answer is the last
+    /// emphasised code span. Parts without a code block reuse the sample of the first part.
+    pub fn parse(html: &str) -> Self {
+        let parts = html
+            .split("<article")
+            // The text before the first article is not part of any part.
+            .skip(1)
+            .filter_map(|article| article.split_once("</article>"))
+            .map(|(article, _)| Example {
+                sample: code_blocks(article)
+                    .into_iter()
+                    .rev()
+                    .max_by_key(|block| block.len()),
+                answer: emphasised(article).pop(),
+            })
+            .collect::<Vec<_>>();
+
+        let first = parts.first().and_then(|part| part.sample.clone());
+        let parts = parts
+            .into_iter()
+            .map(|part| Example {
+                sample: part.sample.or(first.clone()),
+                ..part
+            })
+            .collect();
+
+        Description { parts }
+    }
+
+    /// Returns the path and contents of every distinct sample within the given directory,
+    /// followed by the manifest of answers.
+    ///
+    /// Samples are named `sample_N.txt` in order of appearance.
+    pub fn files(&self, dir: &Path) -> Vec<(PathBuf, String)> {
+        let mut samples = Vec::<&str>::new();
+        let mut manifest = Manifest::default();
+
+        self.parts.iter().zip(1..).for_each(|(part, index)| {
+            let Some(sample) = part.sample.as_deref() else {
+                return;
+            };
+
+            // Reuse the file of an identical sample from an earlier part.
+            let position = samples
+                .iter()
+                .position(|other| *other == sample)
+                .unwrap_or_else(|| {
+                    samples.push(sample);
+                    samples.len() - 1
+                });
+
+            if let Some(answer) = &part.answer {
+                manifest.entries.push(Expected {
+                    part: index,
+                    sample: format!("sample_{}.txt", position + 1),
+                    answer: answer.clone(),
+                });
+            }
+        });
+
+        samples
+            .iter()
+            .zip(1..)
+            .map(|(sample, index)| (dir.join(format!("sample_{index}.txt")), sample.to_string()))
+            .chain([(dir.join(MANIFEST), manifest.to_string())])
+            .collect()
+    }
+
+    /// Write every distinct sample to the given directory, along with a manifest of answers.
+    ///
+    /// Returns every path written, as given by `Description::files`.
+    pub fn write(&self, dir: &Path) -> Result<Vec<PathBuf>, String> {
+        fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+
+        self.files(dir)
+            .into_iter()
+            .map(|(path, contents)| {
+                fs::write(&path, contents)
+                    .map(|_| path)
+                    .map_err(|err| err.to_string())
+            })
+            .collect()
+    }
+}
+
+/// Represents the expected answer of a single part for one of its samples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// Represents the part of the puzzle.
+    pub part: u32,
+    /// Represents the file name of the sample, within the samples directory.
+    pub sample: String,
+    /// Represents the expected answer, as rendered by the solver.
+    pub answer: String,
+}
+
+/// Expected answers of the samples of a single day, stored as one tab-separated line each.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// Represents every expected answer, in order.
+    pub entries: Vec<Expected>,
+}
+
+impl Manifest {
+    /// Parse a manifest from its text form, skipping blank lines.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            // Split each line into its three fields, reporting the line of the first failure.
+            .map(|(index, line)| {
+                let invalid = || format!("line {}: invalid expectation", index + 1);
+
+                let [part, sample, answer] = line.split('\t').collect::<Vec<_>>()[..] else {
+                    return Err(invalid());
+                };
+
+                Ok(Expected {
+                    part: part.parse().map_err(|_| invalid())?,
+                    sample: sample.to_string(),
+                    answer: answer.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Manifest { entries })
+    }
+
+    /// Load the manifest of the given samples directory.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(MANIFEST);
+        let text = fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+        Self::parse(&text)
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.entries
+            .iter()
+            .try_for_each(|entry| writeln!(f, "{}\t{}\t{}", entry.part, entry.sample, entry.answer))
+    }
+}
+
+/// Returns the samples directory next to the given source file, as given by `file!()`.
+pub fn samples_dir(source: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(source)
+        .with_file_name("samples")
+}
+
+/// Returns every sample of the given part with its expected answer, from the manifest of
+/// the samples directory next to the given source file.
+///
+/// Panics if the manifest or any sample cannot be read, or the part has no expected answer,
+/// since it is only used by tests.
+pub fn expected(source: &str, part: u32) -> Vec<(String, String)> {
+    let dir = samples_dir(source);
+    let manifest = Manifest::load(&dir).unwrap();
+
+    let expected = manifest
+        .entries
+        .into_iter()
+        .filter(|entry| entry.part == part)
+        .map(|entry| {
+            let sample = fs::read_to_string(dir.join(&entry.sample)).unwrap();
+            (sample, entry.answer)
+        })
+        .collect::<Vec<_>>();
+
+    assert!(!expected.is_empty(), "no expected answer for part {part}");
+    expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks() {
+        let html = "<p>For example:</p>\n<pre><code>3   4\n<em>4</em>   3\n</code></pre>\n\
+                    <p>Or:</p><pre><code>a &lt;b&gt; &amp; c\n</code></pre>";
+
+        assert_eq!(code_blocks(html), vec!["3   4\n4   3\n", "a <b> & c\n"]);
+        assert_eq!(decode("R&amp;D &copy;"), "R&D &copy;");
+        assert!(code_blocks("<p>No samples</p>").is_empty());
+    }
+
+    #[test]
+    fn descriptions() {
+        let html = concat!(
+            "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2>",
+            "<p>For example:</p><pre><code>3   4\n4   3\n2   5\n</code></pre>",
+            "<p>The pair <code>3   4</code> is close, and in total <code><em>5</em></code>.</p>",
+            "</article><p>Your puzzle answer was <code>1</code>.</p>",
+            "<article class=\"day-desc\"><h2>--- Part Two ---</h2>",
+            "<p>The score is <code><em>1</em></code>, then <code><em>19</em></code>.</p>",
+            "</article></main>",
+        );
+
+        let description = Description::parse(html);
+        assert_eq!(
+            description.parts,
+            vec![
+                Example {
+                    sample: Some("3   4\n4   3\n2   5\n".to_string()),
+                    answer: Some("5".to_string()),
+                },
+                Example {
+                    sample: Some("3   4\n4   3\n2   5\n".to_string()),
+                    answer: Some("19".to_string()),
+                },
+            ]
+        );
+
+        let dir = std::env::temp_dir().join(format!("aoc-2024-puzzle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(description.write(&dir).unwrap().len(), 2);
+
+        let manifest = Manifest::load(&dir).unwrap();
+        assert_eq!(
+            manifest.to_string(),
+            "1\tsample_1.txt\t5\n2\tsample_1.txt\t19\n"
+        );
+        assert_eq!(Manifest::parse(&manifest.to_string()), Ok(manifest));
+        assert!(samples_dir("src/day_01/mod.rs").ends_with("src/day_01/samples"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,182 @@
+use std::{fmt::Display, fs, io, path::PathBuf, time::Instant};
+
+use crate::common::grid::{Format, Style};
+
+/// Represents the only year whose solutions are registered with `cargo aoc`.
+pub const AOC_RUNNER_YEAR: u32 = 2024;
+
+/// Represents a single solution registered with the runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    /// Represents the day of the puzzle, starting from 1.
+    pub day: u32,
+    /// Represents the part of the puzzle, either 1 or 2.
+    pub part: u32,
+    /// Represents the name of an alternative solver for the same part, if any.
+    pub name: Option<&'static str>,
+    /// Represents the solver, rendering its answer or the reason it has none as a string.
+    pub solve: fn(&str) -> Result<String, String>,
+}
+
+/// Converts the output of a solver into its answer, or the reason it has none.
+pub trait Answer {
+    /// Returns the answer rendered as a string, or the reason there is none.
+    fn answer(self) -> Result<String, String>;
+}
+
+/// Implement `Answer` for types which are always rendered as they are displayed.
+macro_rules! answer {
+    ($($ty:ty),*) => {
+        $(impl Answer for $ty {
+            fn answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answer!(u32, u64, usize, i32, i64, String);
+
+impl<T, E> Answer for Result<T, E>
+where
+    T: Answer,
+    E: Display,
+{
+    fn answer(self) -> Result<String, String> {
+        self.map_err(|err| err.to_string())?.answer()
+    }
+}
+
+/// Represents an extra tool for the puzzle of a single day, beyond its solutions.
+///
+/// Every tool renders its output for the terminal, or the reason it failed.
+#[derive(Debug, Clone, Copy)]
+pub enum Tool {
+    /// Represents printing every step taken while solving the input.
+    Trace(fn(&str) -> Result<String, String>),
+    /// Represents highlighting the matches of both parts in the input, in the given style.
+    Visualize(fn(&str, Style) -> Result<String, String>),
+    /// Represents describing the input as a table, or as JSON if the flag is set.
+    Analyze(fn(&str, bool) -> Result<String, String>),
+    /// Represents encoding an image of the input in the given format, highlighting the
+    /// matches of the given part.
+    Export(fn(&str, u32, Format) -> Result<Vec<u8>, String>),
+    /// Represents drawing the rules of the input, or of the update with the given 1-based
+    /// index, as a Mermaid flowchart if the flag is set or a DOT document otherwise.
+    Graph(fn(&str, Option<usize>, bool) -> Result<String, String>),
+    /// Represents listing every near-miss fragment in the input.
+    Lint(fn(&str) -> Result<String, String>),
+}
+
+/// Represents every solution and tool registered for a single year.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    /// Represents the year of the puzzles.
+    pub year: u32,
+    /// Represents every solution of the year, ordered by day and part.
+    pub solutions: &'static [Solution],
+    /// Represents the extra tools of the year, each with the day it applies to.
+    pub tools: &'static [(u32, Tool)],
+}
+
+impl Year {
+    /// Returns the tool of the given day picked out by `select`, if the day registers one.
+    pub fn tool<F, T>(&self, day: u32, select: F) -> Option<T>
+    where
+        F: Fn(Tool) -> Option<T>,
+    {
+        self.tools
+            .iter()
+            .filter(|(tool_day, _)| *tool_day == day)
+            .find_map(|(_, tool)| select(*tool))
+    }
+}
+
+/// Register a solver function for the given day and part, optionally naming an alternative
+/// solver in the same way as `#[aoc(dayN, partM, Name)]`.
+macro_rules! solution {
+    ($day:literal, $part:literal, $solve:path) => {
+        Solution {
+            day: $day,
+            part: $part,
+            name: None,
+            solve: |input| $crate::runner::Answer::answer($solve(input)),
+        }
+    };
+    ($day:literal, $part:literal, $name:ident, $solve:path) => {
+        Solution {
+            day: $day,
+            part: $part,
+            name: Some(stringify!($name)),
+            solve: |input| $crate::runner::Answer::answer($solve(input)),
+        }
+    };
+}
+
+pub(crate) use solution;
+
+/// Represents every registered year, in order.
+pub const YEARS: &[Year] = &[crate::year_2024::YEAR];
+
+/// Returns the solutions registered for the given year, if any.
+pub fn find_year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|registered| registered.year == year)
+}
+
+/// Returns the latest registered year, which commands use unless given another.
+pub fn latest_year() -> u32 {
+    YEARS
+        .iter()
+        .map(|year| year.year)
+        .max()
+        .unwrap_or(AOC_RUNNER_YEAR)
+}
+
+/// Returns the path of the puzzle input for the given day of the given year.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("input/{year}/day{day}.txt"))
+}
+
+/// Returns the path of the history of submitted answers for the given year.
+pub fn history_path(year: u32) -> PathBuf {
+    PathBuf::from(format!("input/{year}/guesses.tsv"))
+}
+
+/// Load the puzzle input for the given day of the given year, without its trailing newlines.
+pub fn load_input(year: u32, day: u32) -> io::Result<String> {
+    let input = fs::read_to_string(input_path(year, day))?;
+
+    // Trim trailing newlines in the same way as `aoc_runner::ArcStr`.
+    Ok(input.trim_end_matches('\n').to_string())
+}
+
+/// Run every registered solution of the given year, optionally restricted to a single day.
+pub fn run(year: &Year, day: Option<u32>) -> io::Result<()> {
+    println!("Advent of code {}", year.year);
+
+    year.solutions
+        .iter()
+        // Only run solutions for the requested day, if any.
+        .filter(|solution| day.map_or(true, |day| day == solution.day))
+        // Run each solution against its input and report the answer with its timing.
+        .try_for_each(|solution| {
+            let input = load_input(year.year, solution.day)?;
+
+            let start_time = Instant::now();
+            let result = (solution.solve)(&input).unwrap_or_else(|err| format!("error: {err}"));
+            let final_time = Instant::now();
+
+            println!(
+                "Day {} - Part {}{}: {}\n\trunner: {:?}\n",
+                solution.day,
+                solution.part,
+                solution
+                    .name
+                    .map_or(String::new(), |name| format!(" - {name}")),
+                result,
+                final_time - start_time
+            );
+
+            Ok(())
+        })
+}
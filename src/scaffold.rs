@@ -0,0 +1,420 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    puzzle::{Description, MANIFEST},
+    runner::AOC_RUNNER_YEAR,
+};
+
+/// Represents the module of a new day, where `{day}` is replaced by the day number and
+/// `{aoc}` by the `cargo aoc` registration of the following solver, if any.
+const TEMPLATE: &str = r#"{aoc:1}pub fn solve_part_1(input: &str) -> u32 {
+    todo!("solve day {day} part 1 for {} bytes of input", input.len())
+}
+
+{aoc:2}pub fn solve_part_2(input: &str) -> u32 {
+    todo!("solve day {day} part 2 for {} bytes of input", input.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::expected;
+
+    #[test]
+    fn case_1() {
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).to_string(), answer);
+            });
+    }
+
+    #[test]
+    fn case_2() {
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).to_string(), answer);
+            });
+    }
+}
+"#;
+
+/// Represents the module of a new year, where `{year}` is replaced by the year.
+const YEAR_TEMPLATE: &str = r#"use crate::runner::{solution, Solution, Year};
+
+/// Represents every solution and tool of {year}, ordered by day and part.
+///
+/// Solvers annotated with `#[aoc]` for `cargo aoc` must also be registered here.
+pub const YEAR: Year = Year {
+    year: {year},
+    solutions: &[],
+    tools: &[],
+};
+"#;
+
+/// Returns the name of the module of the given day.
+pub fn module_name(day: u32) -> String {
+    format!("day_{day:02}")
+}
+
+/// Returns the name of the module of the given year.
+pub fn year_module_name(year: u32) -> String {
+    format!("year_{year}")
+}
+
+/// Returns the directory of the module of the given day, within the crate at the given root.
+pub fn day_dir(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join("src")
+        .join(year_module_name(year))
+        .join(module_name(day))
+}
+
+/// Returns the module of a new day, with both parts left to solve.
+///
+/// Only days of the year registered with `cargo aoc` are annotated for it.
+pub fn template(year: u32, day: u32) -> String {
+    let aoc = |part: u32| match year == AOC_RUNNER_YEAR {
+        true => format!("#[aoc(day{day}, part{part})]\n"),
+        false => String::new(),
+    };
+
+    TEMPLATE
+        .replace("{aoc:1}", &aoc(1))
+        .replace("{aoc:2}", &aoc(2))
+        .replace("{day}", &day.to_string())
+}
+
+/// Returns the module of a new year, without any solutions.
+pub fn year_template(year: u32) -> String {
+    YEAR_TEMPLATE.replace("{year}", &year.to_string())
+}
+
+/// Declare the given module in the given source, in order with modules of the same kind.
+///
+/// Modules are of the same kind when they share a prefix, like `day_` or `year_`. Returns
+/// `None` if the module is already declared.
+pub fn register_module(source: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {module};");
+    let prefix = format!("pub mod {}", &module[..=module.find('_')?]);
+
+    let mut lines = source.lines().collect::<Vec<_>>();
+    if lines.contains(&declaration.as_str()) {
+        return None;
+    }
+
+    // Insert before the first later module, or after the last earlier module.
+    let is_kind = |line: &&str| line.starts_with(&prefix);
+    let index = lines
+        .iter()
+        .position(|line| is_kind(line) && *line > declaration.as_str())
+        .or_else(|| lines.iter().rposition(is_kind).map(|index| index + 1));
+
+    match index {
+        Some(index) => lines.insert(index, &declaration),
+        // Otherwise start a new group of declarations after the imports.
+        None => {
+            let index = lines.iter().rposition(|line| line.starts_with("use "))?;
+            lines.splice(index + 1..index + 1, ["", &declaration]);
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add both parts of the given day to the solutions in the source of a year module.
+///
+/// Returns `None` if the day is already registered, or the source has no solution list.
+pub fn register_solutions(source: &str, day: u32) -> Option<String> {
+    let module = module_name(day);
+    if source.contains(&format!("{module}::")) {
+        return None;
+    }
+
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    let solutions = (1..=2)
+        .map(|part| format!("        solution!({day}, {part}, {module}::solve_part_{part}),"))
+        .collect::<Vec<_>>();
+
+    // Append the solutions after the last registered one, or fill an empty list.
+    match lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("solution!("))
+    {
+        Some(index) => {
+            lines.splice(index + 1..index + 1, solutions);
+        }
+        None => {
+            let index = lines
+                .iter()
+                .position(|line| line.trim() == "solutions: &[],")?;
+            lines.splice(
+                index..=index,
+                ["    solutions: &[".to_string()]
+                    .into_iter()
+                    .chain(solutions)
+                    .chain(["    ],".to_string()]),
+            );
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add the given year to the registered years in the source of `runner.rs`.
+///
+/// Returns `None` if the year is already registered, or the source has no list of years.
+pub fn register_year(source: &str, year: u32) -> Option<String> {
+    let entry = format!("crate::{}::YEAR", year_module_name(year));
+    if source.contains(&entry) {
+        return None;
+    }
+
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    let line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub const YEARS: &[Year] = &["))?;
+
+    let mut years = line
+        .trim_start_matches("pub const YEARS: &[Year] = &[")
+        .trim_end_matches("];")
+        .split(", ")
+        .filter(|year| !year.is_empty())
+        .map(str::to_string)
+        .chain([entry])
+        .collect::<Vec<_>>();
+    years.sort();
+    *line = format!("pub const YEARS: &[Year] = &[{}];", years.join(", "));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Generate the module of a new day within the crate at the given root, and register it.
+///
+/// The module of the year is generated and registered as well, if it does not exist yet.
+/// Samples and expected answers are taken from the puzzle description, if any, and
+/// otherwise left empty. Returns every path which was written.
+pub fn scaffold(
+    root: &Path,
+    year: u32,
+    day: u32,
+    description: Option<&Description>,
+) -> Result<Vec<PathBuf>, String> {
+    let dir = day_dir(root, year, day);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let year_path = root.join("src").join(year_module_name(year)).join("mod.rs");
+    let read = |path: &Path| fs::read_to_string(path).map_err(|err| err.to_string());
+
+    // Update the sources in memory first, so that nothing is written if any fails.
+    let mut sources = Vec::new();
+
+    let year_source = match year_path.exists() {
+        true => read(&year_path)?,
+        false => {
+            let lib_path = root.join("src/lib.rs");
+            let runner_path = root.join("src/runner.rs");
+
+            let lib = register_module(&read(&lib_path)?, &year_module_name(year))
+                .ok_or_else(|| format!("{year} is already declared in lib.rs"))?;
+            let runner = register_year(&read(&runner_path)?, year)
+                .ok_or_else(|| format!("{year} cannot be registered in runner.rs"))?;
+
+            sources.extend([(lib_path, lib), (runner_path, runner)]);
+            year_template(year)
+        }
+    };
+
+    let year_source = register_module(&year_source, &module_name(day))
+        .and_then(|source| register_solutions(&source, day))
+        .ok_or_else(|| format!("day {day} cannot be registered in {}", year_path.display()))?;
+    sources.insert(0, (year_path, year_source));
+
+    let samples_dir = dir.join("samples");
+    let samples = match description {
+        Some(description) => description.files(&samples_dir),
+        None => vec![
+            (samples_dir.join("sample_1.txt"), String::new()),
+            (samples_dir.join(MANIFEST), String::new()),
+        ],
+    };
+
+    // Write the new day before the sources registering it, undoing every write if any fails.
+    let files = [(dir.join("mod.rs"), template(year, day))]
+        .into_iter()
+        .chain(samples)
+        .chain(sources)
+        .collect::<Vec<_>>();
+    write_all(&files)?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Write every file in order, creating their parent directories.
+///
+/// If any write fails, every file already written is restored to its previous contents, or
+/// removed if it did not exist, and every directory created along the way is removed.
+fn write_all(files: &[(PathBuf, String)]) -> Result<(), String> {
+    let mut created = Vec::new();
+    let mut written = Vec::new();
+
+    let result = files.iter().try_for_each(|(path, contents)| {
+        let parent = path.parent().unwrap_or(Path::new(""));
+
+        // Remember the outermost directory which does not exist yet.
+        if let Some(dir) = parent.ancestors().take_while(|dir| !dir.exists()).last() {
+            created.push(dir.to_path_buf());
+        }
+        fs::create_dir_all(parent)?;
+
+        let previous = fs::read_to_string(path).ok();
+        fs::write(path, contents)?;
+        written.push((path, previous));
+
+        Ok(())
+    });
+
+    result.map_err(|err: io::Error| {
+        // Undo every write in reverse, ignoring failures as the original error matters more.
+        written.iter().rev().for_each(|(path, previous)| {
+            let _ = match previous {
+                Some(previous) => fs::write(path, previous),
+                None => fs::remove_file(path),
+            };
+        });
+        created.iter().rev().for_each(|dir| {
+            let _ = fs::remove_dir_all(dir);
+        });
+
+        format!("{err}, nothing was written")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::puzzle::Example;
+
+    const LIB: &str =
+        "pub mod cli;\npub mod runner;\n\npub mod year_2024;\n\naoc_lib! { year = 2024 }\n";
+    const RUNNER: &str = "pub const YEARS: &[Year] = &[crate::year_2024::YEAR];\n";
+
+    /// Returns the module of 2024 with only day 5 registered.
+    fn year() -> String {
+        let year = register_module(&year_template(2024), "day_05").unwrap();
+        register_solutions(&year, 5).unwrap()
+    }
+
+    #[test]
+    fn registration() {
+        let lib = "pub mod cli;\n\npub mod day_01;\npub mod day_03;\n\naoc_lib! { year = 2024 }\n";
+        assert_eq!(
+            register_module(lib, "day_02").unwrap(),
+            lib.replace("day_01;\n", "day_01;\npub mod day_02;\n")
+        );
+        assert_eq!(
+            register_module(lib, "day_12").unwrap(),
+            lib.replace("day_03;\n", "day_03;\npub mod day_12;\n")
+        );
+        assert_eq!(register_module(lib, "day_03"), None);
+
+        let registered = register_solutions(&year(), 6).unwrap();
+        assert!(registered.contains(
+            "        solution!(5, 2, day_05::solve_part_2),\n        solution!(6, 1, day_06::solve_part_1),\n"
+        ));
+        assert_eq!(register_solutions(&registered, 6), None);
+
+        // A new year starts its own group of days and its own list of solutions.
+        let year = register_module(&year_template(2025), "day_01").unwrap();
+        let year = register_solutions(&year, 1).unwrap();
+        assert!(year.contains("Year};\n\npub mod day_01;\n\n/// Represents"));
+        assert!(year.contains(
+            "    solutions: &[\n        solution!(1, 1, day_01::solve_part_1),\n        solution!(1, 2, day_01::solve_part_2),\n    ],\n"
+        ));
+
+        let registered = register_year(RUNNER, 2025).unwrap();
+        assert!(registered.contains(
+            "pub const YEARS: &[Year] = &[crate::year_2024::YEAR, crate::year_2025::YEAR];"
+        ));
+        assert_eq!(register_year(&registered, 2025), None);
+    }
+
+    #[test]
+    fn generation() {
+        let root = env::temp_dir().join(format!("aoc-2024-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/year_2024")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/runner.rs"), RUNNER).unwrap();
+        fs::write(root.join("src/year_2024/mod.rs"), year()).unwrap();
+
+        let description = Description {
+            parts: vec![Example {
+                sample: Some("....#.....\n".to_string()),
+                answer: Some("41".to_string()),
+            }],
+        };
+        let paths = scaffold(&root, 2024, 6, Some(&description)).unwrap();
+        assert_eq!(paths.len(), 4);
+
+        let module = fs::read_to_string(root.join("src/year_2024/day_06/mod.rs")).unwrap();
+        assert!(module.starts_with("#[aoc(day6, part1)]\npub fn solve_part_1"));
+        assert!(module.contains("fn case_2()"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/year_2024/day_06/samples/expected.tsv")).unwrap(),
+            "1\tsample_1.txt\t41\n"
+        );
+        assert!(fs::read_to_string(root.join("src/year_2024/mod.rs"))
+            .unwrap()
+            .contains("pub mod day_05;\npub mod day_06;\n"));
+
+        // A day is never generated twice.
+        assert!(scaffold(&root, 2024, 6, None).is_err());
+
+        // The first day of a new year registers the year, without `cargo aoc` annotations.
+        assert_eq!(scaffold(&root, 2025, 1, None).unwrap().len(), 6);
+        let module = fs::read_to_string(root.join("src/year_2025/day_01/mod.rs")).unwrap();
+        assert!(module.starts_with("pub fn solve_part_1"));
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod year_2024;\npub mod year_2025;\n"));
+
+        // A failed write leaves nothing behind, so that the day can be generated again.
+        fs::write(root.join("src/year_2026"), "").unwrap();
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(scaffold(&root, 2026, 1, None).is_err());
+        assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), lib);
+        fs::remove_file(root.join("src/year_2026")).unwrap();
+        assert!(scaffold(&root, 2026, 1, None).is_ok());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn rollback() {
+        let root = env::temp_dir().join(format!("aoc-2024-rollback-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("old.txt"), "old").unwrap();
+
+        // The last file cannot be written, as its parent is a file.
+        let files = [
+            (root.join("old.txt"), "new".to_string()),
+            (root.join("new/file.txt"), "new".to_string()),
+            (root.join("old.txt/file.txt"), "new".to_string()),
+        ];
+        assert!(write_all(&files).is_err());
+
+        assert_eq!(fs::read_to_string(root.join("old.txt")).unwrap(), "old");
+        assert!(!root.join("new").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
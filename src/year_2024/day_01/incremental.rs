@@ -0,0 +1,479 @@
+use std::collections::HashMap;
+
+/// Selects which of the two input columns an edit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// Represents the left column of the input statement.
+    Left,
+    /// Represents the right column of the input statement.
+    Right,
+}
+
+/// Represents the number of values covered by a `DiffTree`, which is every `u32`.
+const DOMAIN: u64 = 1 << 32;
+
+/// Represents one node of a `DiffTree`, covering a contiguous range of values.
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    /// Represents the smallest difference within the range.
+    min: i64,
+    /// Represents the number of values holding the smallest difference.
+    at_min: u64,
+    /// Represents the largest difference within the range.
+    max: i64,
+    /// Represents the number of values holding the largest difference.
+    at_max: u64,
+    /// Represents the total difference within the range.
+    sum: i64,
+    /// Represents the total absolute difference within the range.
+    sum_abs: u64,
+    /// Represents a pending offset which has not been pushed to the children.
+    lazy: i64,
+    /// Represents the index of the left child, the right child following it.
+    ///
+    /// Nodes without children hold the same difference for every value in their range.
+    children: Option<usize>,
+}
+
+impl Node {
+    /// Creates a node holding the same difference for `len` values.
+    fn uniform(diff: i64, len: u64) -> Self {
+        Node {
+            min: diff,
+            at_min: len,
+            max: diff,
+            at_max: len,
+            sum: diff * len as i64,
+            sum_abs: diff.unsigned_abs() * len,
+            lazy: 0,
+            children: None,
+        }
+    }
+
+    /// Returns whether the total absolute difference follows from the summary alone,
+    /// which holds while no difference is below `-1` or none is above `1`.
+    fn is_banded(min: i64, max: i64) -> bool {
+        min >= -1 || max <= 1
+    }
+}
+
+/// Segment tree over every `u32` holding `D(x) = #{left <= x} - #{right <= x}`.
+///
+/// For two sorted columns of equal length, the sum of pairwise distances is equal
+/// to the sum of `|D(x)|` over every unit interval `[x, x + 1)`. Inserting a value
+/// `v` into a column adds `±1` to every `D(x)` with `x >= v`, so keeping the sum of
+/// absolute values up to date only needs a lazy suffix update.
+///
+/// While every difference within a node is at least `-1`, the absolute differences
+/// total the plain sum plus twice the number of values at `-1`, and symmetrically
+/// while every difference is at most `1`. Nodes keep the plain sum along with their
+/// extremes and how often each occurs, so an offset is applied in `O(1)` whenever
+/// it leaves the node within either band, and pushing an offset down to the children
+/// of such a node never needs to descend further.
+///
+/// Nodes are only allocated where `D` changes, so memory grows by at most one path
+/// of 32 nodes per update, whatever the values. An update visits `O(log V)` nodes,
+/// plus `O(log V)` for every time `D` swings from `-2` or below to `2` or above (or
+/// back) within the updated suffix. Columns which are nearly equal, or which only
+/// ever lead each other by one, are therefore updated in `O(log V)`.
+#[derive(Debug, Clone)]
+struct DiffTree {
+    /// Represents the nodes of the tree, rooted at index `0`.
+    nodes: Vec<Node>,
+    /// Represents the number of nodes visited by every update so far.
+    visits: u64,
+}
+
+impl DiffTree {
+    /// Creates a tree where every difference is zero.
+    fn new() -> Self {
+        DiffTree {
+            nodes: vec![Node::uniform(0, DOMAIN)],
+            visits: 0,
+        }
+    }
+
+    /// Applies `delta` to every value in `node`, which covers `len` values.
+    ///
+    /// The node must be left within one of the bands described by `Node::is_banded`.
+    fn apply(&mut self, node: usize, len: u64, delta: i64) {
+        let target = &mut self.nodes[node];
+        target.min += delta;
+        target.max += delta;
+        target.sum += delta * len as i64;
+        target.lazy += delta;
+
+        // Values at the edge of the band count twice, having the opposite sign to the sum.
+        let Node {
+            min,
+            at_min,
+            max,
+            at_max,
+            sum,
+            ..
+        } = *target;
+        let sum_abs = match min >= -1 {
+            true => sum + 2 * (min == -1) as i64 * at_min as i64,
+            false => -sum + 2 * (max == 1) as i64 * at_max as i64,
+        };
+        target.sum_abs = sum_abs as u64;
+    }
+
+    /// Returns whether `delta` can be applied to `node` without descending.
+    fn is_uniform(&self, node: usize, delta: i64) -> bool {
+        let Node { min, max, .. } = self.nodes[node];
+
+        Node::is_banded(min + delta, max + delta)
+    }
+
+    /// Returns the left child of `node`, which covers `[lo, hi)`, allocating both children
+    /// or pushing the pending offset down to them.
+    fn split(&mut self, node: usize, lo: u64, hi: u64) -> usize {
+        let lazy = std::mem::take(&mut self.nodes[node].lazy);
+        let half = (hi - lo) / 2;
+
+        match self.nodes[node].children {
+            Some(left) => {
+                // Both children lie within the band the offset left this node in.
+                if lazy != 0 {
+                    self.apply(left, half, lazy);
+                    self.apply(left + 1, half, lazy);
+                }
+
+                left
+            }
+            None => {
+                // Both halves start with the single difference held by the node.
+                let child = Node::uniform(self.nodes[node].min, half);
+
+                let left = self.nodes.len();
+                self.nodes.extend([child, child]);
+                self.nodes[node].children = Some(left);
+
+                left
+            }
+        }
+    }
+
+    /// Recomputes the summary of `node` from both of its children.
+    fn pull(&mut self, node: usize, left: usize) {
+        let (lhs, rhs) = (self.nodes[left], self.nodes[left + 1]);
+
+        // Count the occurrences of an extreme across whichever children hold it.
+        let count = |extreme: i64, (lhs, at_lhs): (i64, u64), (rhs, at_rhs): (i64, u64)| {
+            (lhs == extreme) as u64 * at_lhs + (rhs == extreme) as u64 * at_rhs
+        };
+
+        let target = &mut self.nodes[node];
+        target.min = lhs.min.min(rhs.min);
+        target.at_min = count(target.min, (lhs.min, lhs.at_min), (rhs.min, rhs.at_min));
+        target.max = lhs.max.max(rhs.max);
+        target.at_max = count(target.max, (lhs.max, lhs.at_max), (rhs.max, rhs.at_max));
+        target.sum = lhs.sum + rhs.sum;
+        target.sum_abs = lhs.sum_abs + rhs.sum_abs;
+    }
+
+    /// Adds `delta` to every difference at or above `from` below `node`, which covers `[lo, hi)`.
+    fn update_inner(&mut self, node: usize, lo: u64, hi: u64, from: u64, delta: i64) {
+        self.visits += 1;
+
+        // Base Case: the node is entirely below the updated suffix.
+        if hi <= from {
+            return;
+        }
+
+        // Base Case: the node is covered and stays within a band after the update.
+        if from <= lo && self.is_uniform(node, delta) {
+            return self.apply(node, hi - lo, delta);
+        }
+
+        // Recursive Case: split the node and update both halves independently.
+        let mid = lo + (hi - lo) / 2;
+        let left = self.split(node, lo, hi);
+
+        self.update_inner(left, lo, mid, from, delta);
+        self.update_inner(left + 1, mid, hi, from, delta);
+        self.pull(node, left);
+    }
+
+    /// Adds `delta` to every difference at or above `value`.
+    fn update_suffix(&mut self, value: u32, delta: i64) {
+        self.update_inner(0, 0, DOMAIN, value as u64, delta);
+    }
+
+    /// Returns the sum of all absolute differences.
+    fn sum_abs(&self) -> u64 {
+        self.nodes[0].sum_abs
+    }
+}
+
+/// Incremental view of both input columns, supporting insertions and removals.
+///
+/// The similarity score is updated in `O(1)` per edit and the sorted-pair distance
+/// is maintained by a `DiffTree`, so neither requires re-sorting the columns. See
+/// `DiffTree` for the cost of updating the distance, which depends on the columns.
+#[derive(Debug, Clone)]
+pub struct Incremental {
+    /// Represents the occurrences of every value in the left column.
+    left: HashMap<u32, u32>,
+    /// Represents the occurrences of every value in the right column.
+    right: HashMap<u32, u32>,
+    /// Represents the number of values in the left column.
+    left_len: usize,
+    /// Represents the number of values in the right column.
+    right_len: usize,
+    /// Represents the current similarity score.
+    similarity: u64,
+    /// Represents the running difference between both columns over the value domain.
+    tree: DiffTree,
+}
+
+impl Default for Incremental {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Incremental {
+    /// Creates an empty view with no values in either column.
+    pub fn new() -> Self {
+        Incremental {
+            left: HashMap::new(),
+            right: HashMap::new(),
+            left_len: 0,
+            right_len: 0,
+            similarity: 0,
+            tree: DiffTree::new(),
+        }
+    }
+
+    /// Creates a view from both columns of an input statement.
+    pub fn from_columns<L, R>(left: L, right: R) -> Self
+    where
+        L: IntoIterator<Item = u32>,
+        R: IntoIterator<Item = u32>,
+    {
+        let mut view = Self::new();
+
+        // Insert every value from both columns in turn.
+        left.into_iter()
+            .for_each(|num| view.insert(Column::Left, num));
+        right
+            .into_iter()
+            .for_each(|num| view.insert(Column::Right, num));
+
+        view
+    }
+
+    /// Creates a view from the input statement.
+    pub fn parse<S>(input: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let (left, right): (Vec<_>, Vec<_>) = super::collect_columns(input);
+
+        Self::from_columns(left, right)
+    }
+
+    /// Returns the occurrence map of the column opposite to the given one.
+    fn other_counts(&self, column: Column) -> &HashMap<u32, u32> {
+        match column {
+            Column::Left => &self.right,
+            Column::Right => &self.left,
+        }
+    }
+
+    /// Applies a single occurrence of `value` to the given column with the given sign.
+    fn apply(&mut self, column: Column, value: u32, sign: i64) {
+        // Every occurrence of `value` on the other side pairs with this one.
+        let paired = self.other_counts(column).get(&value).copied().unwrap_or(0) as u64;
+
+        let similarity = value as u64 * paired;
+        self.similarity = match sign > 0 {
+            true => self.similarity + similarity,
+            false => self.similarity - similarity,
+        };
+
+        // The left column counts upwards and the right column counts downwards.
+        let delta = match column {
+            Column::Left => sign,
+            Column::Right => -sign,
+        };
+        self.tree.update_suffix(value, delta);
+    }
+
+    /// Inserts one occurrence of `value` into the given column.
+    pub fn insert(&mut self, column: Column, value: u32) {
+        self.apply(column, value, 1);
+
+        match column {
+            Column::Left => {
+                *self.left.entry(value).or_insert(0) += 1;
+                self.left_len += 1;
+            }
+            Column::Right => {
+                *self.right.entry(value).or_insert(0) += 1;
+                self.right_len += 1;
+            }
+        }
+    }
+
+    /// Removes one occurrence of `value` from the given column.
+    ///
+    /// Returns `None` if the column does not contain `value`.
+    pub fn remove(&mut self, column: Column, value: u32) -> Option<()> {
+        let (counts, len) = match column {
+            Column::Left => (&mut self.left, &mut self.left_len),
+            Column::Right => (&mut self.right, &mut self.right_len),
+        };
+
+        // Decrement the occurrences, dropping the entry once none remain.
+        let count = counts.get_mut(&value)?;
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(&value);
+        }
+        *len -= 1;
+
+        self.apply(column, value, -1);
+
+        Some(())
+    }
+
+    /// Returns the number of values in the given column.
+    pub fn len(&self, column: Column) -> usize {
+        match column {
+            Column::Left => self.left_len,
+            Column::Right => self.right_len,
+        }
+    }
+
+    /// Returns whether both columns are empty.
+    pub fn is_empty(&self) -> bool {
+        self.left_len == 0 && self.right_len == 0
+    }
+
+    /// Returns the total distance between sorted pairs from both columns.
+    ///
+    /// Pairs are only well-defined while both columns hold the same number of values,
+    /// so this returns `None` while an edit on one side has not been matched yet.
+    pub fn distance(&self) -> Option<u64> {
+        (self.left_len == self.right_len).then(|| self.tree.sum_abs())
+    }
+
+    /// Returns the similarity score of both columns.
+    pub fn similarity(&self) -> u64 {
+        self.similarity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2024::day_01::{solve_part_1, solve_part_2};
+
+    #[test]
+    fn matches_solutions() {
+        let input = include_str!("./samples/sample_1.txt");
+        let view = Incremental::parse(input);
+
+        assert_eq!(view.distance(), Some(solve_part_1(input) as u64));
+        assert_eq!(view.similarity(), solve_part_2(input) as u64);
+    }
+
+    #[test]
+    fn tracks_edits() {
+        let input = include_str!("./samples/sample_1.txt");
+        let mut view = Incremental::parse(input);
+
+        // Replace the left `1` with a `9` and check against a full recomputation.
+        view.remove(Column::Left, 1).unwrap();
+        assert_eq!(view.distance(), None);
+        view.insert(Column::Left, 9);
+
+        let edited = "3   4\n4   3\n2   5\n9   3\n3   9\n3   3";
+        assert_eq!(view.distance(), Some(solve_part_1(edited) as u64));
+        assert_eq!(view.similarity(), solve_part_2(edited) as u64);
+
+        // Values anywhere in the domain only allocate the nodes they need.
+        view.insert(Column::Left, u32::MAX);
+        view.remove(Column::Left, u32::MAX).unwrap();
+        view.insert(Column::Left, 1000);
+        view.insert(Column::Right, 7);
+
+        let edited = "3   4\n4   3\n2   5\n9   3\n3   9\n3   3\n1000   7";
+        assert_eq!(view.distance(), Some(solve_part_1(edited) as u64));
+        assert_eq!(view.remove(Column::Right, 1000), None);
+        assert!(view.tree.nodes.len() < 1000);
+    }
+
+    /// Applies `edit` to `view` and returns the number of nodes it visited.
+    fn visits<F>(view: &mut Incremental, edit: F) -> u64
+    where
+        F: FnOnce(&mut Incremental),
+    {
+        let visits = view.tree.visits;
+        edit(view);
+        view.tree.visits - visits
+    }
+
+    #[test]
+    fn costs() {
+        // Every update may visit two paths from the root down to the value.
+        let bound = 4 * 33;
+
+        // Columns which differ by a single value.
+        let mut view = Incremental::from_columns(0..1000, 0..1000);
+        (0..100).for_each(|_| {
+            assert!(visits(&mut view, |view| view.insert(Column::Left, 500)) <= bound);
+            assert!(visits(&mut view, |view| view.remove(Column::Left, 500).unwrap()) <= bound);
+        });
+        assert_eq!(view.distance(), Some(0));
+
+        // Alternating columns, where the sign of every difference flips with each update.
+        let mut view =
+            Incremental::from_columns((0..500).map(|num| num * 2), (0..500).map(|num| num * 2 + 1));
+        (0..100).for_each(|_| {
+            assert!(visits(&mut view, |view| view.insert(Column::Right, 0)) <= bound);
+            assert!(visits(&mut view, |view| view.remove(Column::Right, 0).unwrap()) <= bound);
+        });
+        assert_eq!(view.distance(), Some(500));
+    }
+
+    #[test]
+    fn random_edits() {
+        // Replace values in both columns from a fixed seed, far apart and close together.
+        let mut state = 7u64;
+        let mut next = |range: u64| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) % range
+        };
+
+        let (mut left, mut right): (Vec<u32>, Vec<u32>) = ((0..200).collect(), (0..200).collect());
+        let mut view = Incremental::from_columns(left.clone(), right.clone());
+
+        (0..500).for_each(|step| {
+            let range = if step % 2 == 0 { 50 } else { u32::MAX as u64 };
+            let (column, values) = match next(2) {
+                0 => (Column::Left, &mut left),
+                _ => (Column::Right, &mut right),
+            };
+
+            let index = next(values.len() as u64) as usize;
+            view.remove(column, values[index]).unwrap();
+            values[index] = next(range) as u32;
+            view.insert(column, values[index]);
+
+            // Recompute the distance from sorted copies, which may exceed a `u32`.
+            let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+            sorted_left.sort();
+            sorted_right.sort();
+            let expected = sorted_left
+                .into_iter()
+                .zip(sorted_right)
+                .map(|(left, right)| left.abs_diff(right) as u64)
+                .sum();
+            assert_eq!(view.distance(), Some(expected));
+        });
+    }
+}
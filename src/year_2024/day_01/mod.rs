@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+pub mod incremental;
+
 /// Collect the input statement into two columns.
 pub fn collect_columns<S, V>(input: S) -> (V, V)
 where
@@ -30,9 +32,9 @@ pub fn solve_part_1(input: &str) -> u32 {
 
     left.into_iter()
         // Iterate over sorted pairs from the left and right columns.
-        .zip(right.into_iter())
+        .zip(right)
         // Calculate the absolute distance between each pair.
-        .map(|(left, right)| (left as i64 - right as i64).abs() as u32)
+        .map(|(left, right)| left.abs_diff(right))
         // Total the absolute distance of all pairs.
         .sum()
 }
@@ -63,16 +65,23 @@ pub fn solve_part_2(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::puzzle::expected;
 
     #[test]
     fn case_1() {
-        let input = include_str!("./samples/sample_1.txt");
-        assert_eq!(solve_part_1(input), 11);
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).to_string(), answer);
+            });
     }
 
     #[test]
     fn case_2() {
-        let input = include_str!("./samples/sample_1.txt");
-        assert_eq!(solve_part_2(input), 31);
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).to_string(), answer);
+            });
     }
 }
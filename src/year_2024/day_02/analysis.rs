@@ -0,0 +1,276 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use super::parse_levels;
+
+/// Render a string as a JSON string literal, escaping quotes, backslashes and control characters.
+fn quote(text: &str) -> String {
+    let escaped = text
+        .chars()
+        .map(|char| match char {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            char if char.is_control() => format!("\\u{:04x}", char as u32),
+            char => char.to_string(),
+        })
+        .collect::<String>();
+
+    format!("\"{escaped}\"")
+}
+
+/// Represents the reason why a report was rejected as unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rejection {
+    /// Represents a level which changes the established direction of the report.
+    DirectionChange,
+    /// Represents a level which differs from the previous one by more than 3.
+    StepTooLarge,
+    /// Represents a level which is equal to the previous one.
+    EqualLevels,
+}
+
+impl Rejection {
+    /// Returns the identifier used for this rejection in tables and JSON.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DirectionChange => "direction_change",
+            Self::StepTooLarge => "step_too_large",
+            Self::EqualLevels => "equal_levels",
+        }
+    }
+
+    /// Find the first level which makes a report unsafe, with the reason it was rejected.
+    ///
+    /// Checks mirror the branches of `Record::build_single` and its siblings: once the
+    /// first pair has established a direction, a reversed step is reported as a direction
+    /// change before its size is considered.
+    pub fn check(levels: &[u32]) -> Option<(usize, Self)> {
+        levels
+            .windows(2)
+            .enumerate()
+            // Fold over adjacent pairs, tracking the direction of the first pair.
+            .try_fold(None, |direction, (idx, pair)| {
+                let (prev, item) = (pair[0], pair[1]);
+                let order = item.cmp(&prev);
+
+                let rejection = match direction {
+                    // The direction of the report has been established and is reversed.
+                    Some(direction) if order != Ordering::Equal && order != direction => {
+                        Some(Self::DirectionChange)
+                    }
+                    // The difference between both levels is too large.
+                    _ if item.abs_diff(prev) > 3 => Some(Self::StepTooLarge),
+                    // Both levels are equal, so the report is neither increasing nor decreasing.
+                    _ if order == Ordering::Equal => Some(Self::EqualLevels),
+                    // Otherwise the step is valid and the direction carries over.
+                    _ => None,
+                };
+
+                match rejection {
+                    Some(rejection) => Err((idx + 1, rejection)),
+                    None => Ok(Some(order)),
+                }
+            })
+            .err()
+    }
+}
+
+/// Represents an unsafe report together with the first offending level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anomaly {
+    /// Represents the zero-based line of the report in the input statement.
+    pub line: usize,
+    /// Represents the zero-based index of the first offending level in the report.
+    pub index: usize,
+    /// Represents the reason why the report was rejected.
+    pub rejection: Rejection,
+}
+
+/// Represents statistics collected over every report in the input statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// Represents the total number of reports.
+    pub reports: usize,
+    /// Represents the number of absolute differences between adjacent levels, by size.
+    pub steps: BTreeMap<u32, usize>,
+    /// Represents every unsafe report, in input order.
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl Analysis {
+    /// Analyze every report in the input statement.
+    pub fn from_input(input: &str) -> Self {
+        let reports = input.lines().map(parse_levels).collect::<Vec<_>>();
+
+        // Count the absolute difference between every pair of adjacent levels.
+        let steps = reports
+            .iter()
+            .flat_map(|levels| levels.windows(2).map(|pair| pair[0].abs_diff(pair[1])))
+            .fold(BTreeMap::new(), |mut steps, step| {
+                *steps.entry(step).or_insert(0) += 1;
+                steps
+            });
+
+        // Collect the first offending level of every unsafe report.
+        let anomalies = reports
+            .iter()
+            .enumerate()
+            .filter_map(|(line, levels)| {
+                Rejection::check(levels).map(|(index, rejection)| Anomaly {
+                    line,
+                    index,
+                    rejection,
+                })
+            })
+            .collect();
+
+        Analysis {
+            reports: reports.len(),
+            steps,
+            anomalies,
+        }
+    }
+
+    /// Returns the number of reports which are safe without any removals.
+    pub fn safe(&self) -> usize {
+        self.reports - self.anomalies.len()
+    }
+
+    /// Returns the number of unsafe reports for each rejection reason.
+    pub fn rejections(&self) -> BTreeMap<Rejection, usize> {
+        self.anomalies
+            .iter()
+            .fold(BTreeMap::new(), |mut rejections, anomaly| {
+                *rejections.entry(anomaly.rejection).or_insert(0) += 1;
+                rejections
+            })
+    }
+
+    /// Render the analysis as a JSON object.
+    pub fn to_json(&self) -> String {
+        let steps = self
+            .steps
+            .iter()
+            .map(|(step, count)| format!("{}:{count}", quote(&step.to_string())))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let rejections = self
+            .rejections()
+            .iter()
+            .map(|(rejection, count)| format!("{}:{count}", quote(rejection.name())))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let anomalies = self
+            .anomalies
+            .iter()
+            .map(|anomaly| {
+                format!(
+                    "{{\"line\":{},\"index\":{},\"rejection\":{}}}",
+                    anomaly.line,
+                    anomaly.index,
+                    quote(anomaly.rejection.name())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "{{\"reports\":{},\"safe\":{},\"steps\":{{{steps}}},\"rejections\":{{{rejections}}},\"anomalies\":[{anomalies}]}}",
+            self.reports,
+            self.safe()
+        )
+    }
+}
+
+/// Analyze the input, rendering the result as a table or as JSON.
+pub fn report(input: &str, json: bool) -> String {
+    let analysis = Analysis::from_input(input);
+
+    match json {
+        true => analysis.to_json(),
+        false => analysis.to_string().trim_end().to_string(),
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "reports: {}", self.reports)?;
+        writeln!(f, "safe:    {}", self.safe())?;
+
+        writeln!(f, "\n{:>6} | {:>6}", "step", "count")?;
+        writeln!(f, "{:->6}-+-{:->6}", "", "")?;
+        self.steps
+            .iter()
+            .try_for_each(|(step, count)| writeln!(f, "{step:>6} | {count:>6}"))?;
+
+        writeln!(f, "\n{:>16} | {:>6}", "rejection", "count")?;
+        writeln!(f, "{:->16}-+-{:->6}", "", "")?;
+        self.rejections()
+            .iter()
+            .try_for_each(|(rejection, count)| {
+                writeln!(f, "{:>16} | {count:>6}", rejection.name())
+            })?;
+
+        writeln!(f, "\n{:>6} | {:>6} | {:>16}", "line", "index", "rejection")?;
+        writeln!(f, "{:->6}-+-{:->6}-+-{:->16}", "", "", "")?;
+        self.anomalies.iter().try_for_each(|anomaly| {
+            writeln!(
+                f,
+                "{:>6} | {:>6} | {:>16}",
+                anomaly.line,
+                anomaly.index,
+                anomaly.rejection.name()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2024::day_02::solve_part_1;
+
+    #[test]
+    fn rejections() {
+        assert_eq!(Rejection::check(&[7, 6, 4, 2, 1]), None);
+        assert_eq!(
+            Rejection::check(&[1, 2, 7, 8, 9]),
+            Some((2, Rejection::StepTooLarge))
+        );
+        assert_eq!(
+            Rejection::check(&[1, 3, 2, 4, 5]),
+            Some((2, Rejection::DirectionChange))
+        );
+        assert_eq!(
+            Rejection::check(&[8, 6, 4, 4, 1]),
+            Some((3, Rejection::EqualLevels))
+        );
+    }
+
+    #[test]
+    fn analysis() {
+        let input = include_str!("./samples/sample_1.txt");
+        let analysis = Analysis::from_input(input);
+
+        assert_eq!(analysis.safe() as u32, solve_part_1(input));
+        assert_eq!(analysis.steps.values().sum::<usize>(), 24);
+        assert!(analysis
+            .to_json()
+            .contains("\"anomalies\":[{\"line\":1,\"index\":2,"));
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote("step_too_large"), "\"step_too_large\"");
+        assert_eq!(quote("a \"b\" \\ c\n"), "\"a \\\"b\\\" \\\\ c\\n\"");
+        assert_eq!(quote("\u{1}"), "\"\\u0001\"");
+    }
+}
@@ -1,5 +1,8 @@
 use std::{cmp::Ordering, iter::once};
 
+pub mod analysis;
+pub mod removals;
+
 /// ...
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Record {
@@ -41,7 +44,7 @@ impl Record {
 
     /// ...
     #[inline]
-    fn build_increasing(margin: usize, items: &[u32], item: u32, seen: &Vec<u32>) -> Option<Self> {
+    fn build_increasing(margin: usize, items: &[u32], item: u32, seen: &[u32]) -> Option<Self> {
         // `Report::Decreasing` will always be non-empty, so this is safe.
         let prev = seen.last().copied().unwrap();
 
@@ -50,7 +53,7 @@ impl Record {
         (item > prev && item.abs_diff(prev) <= 3).then_some(())?;
 
         // ...
-        let seen = seen.clone().into_iter().chain(once(item)).collect();
+        let seen = seen.iter().copied().chain(once(item)).collect();
 
         // ...
         Self::Increasing(seen).try_from_inner(margin, items)
@@ -58,7 +61,7 @@ impl Record {
 
     /// ...
     #[inline]
-    fn build_decreasing(margin: usize, items: &[u32], item: u32, seen: &Vec<u32>) -> Option<Self> {
+    fn build_decreasing(margin: usize, items: &[u32], item: u32, seen: &[u32]) -> Option<Self> {
         // `Report::Decreasing` will always be non-empty, so this is safe.
         let prev = seen.last().copied().unwrap();
 
@@ -67,7 +70,7 @@ impl Record {
         (item < prev && item.abs_diff(prev) <= 3).then_some(())?;
 
         // ...
-        let seen = seen.clone().into_iter().chain(once(item)).collect();
+        let seen = seen.iter().copied().chain(once(item)).collect();
 
         // ...
         Self::Decreasing(seen).try_from_inner(margin, items)
@@ -129,6 +132,13 @@ impl Record {
     }
 }
 
+/// Parse a single line of input into the levels of a report.
+pub fn parse_levels(line: &str) -> Vec<u32> {
+    line.split(" ")
+        .map(|item| item.parse::<u32>().unwrap())
+        .collect()
+}
+
 #[aoc(day2, part1)]
 pub fn solve_part_1(input: &str) -> u32 {
     input
@@ -136,7 +146,7 @@ pub fn solve_part_1(input: &str) -> u32 {
         .lines()
         // ...
         .filter_map(|line| {
-            let line = line.split(" ").map(|item| item.parse::<u32>().unwrap());
+            let line = parse_levels(line);
 
             let record = Record::try_from(0, line).map(Record::unwrap)?;
 
@@ -144,8 +154,7 @@ pub fn solve_part_1(input: &str) -> u32 {
                 .iter()
                 .copied()
                 .zip(record.iter().copied().skip(1))
-                .map(|(x, y)| (x.max(y) - x.min(y)) <= 3)
-                .all(|valid| valid)
+                .all(|(x, y)| (x.max(y) - x.min(y)) <= 3)
                 .then_some(())
         })
         // ...
@@ -159,7 +168,7 @@ pub fn solve_part_2(input: &str) -> u32 {
         .lines()
         // ...
         .filter_map(|line| {
-            let line = line.split(" ").map(|item| item.parse::<u32>().unwrap());
+            let line = parse_levels(line);
 
             let record = Record::try_from(1, line).map(Record::unwrap)?;
 
@@ -167,8 +176,7 @@ pub fn solve_part_2(input: &str) -> u32 {
                 .iter()
                 .copied()
                 .zip(record.iter().copied().skip(1))
-                .map(|(x, y)| (x.max(y) - x.min(y)) <= 3)
-                .all(|valid| valid)
+                .all(|(x, y)| (x.max(y) - x.min(y)) <= 3)
                 .then_some(())
         })
         // ...
@@ -178,16 +186,23 @@ pub fn solve_part_2(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::puzzle::expected;
 
     #[test]
     fn case_1() {
-        let input = include_str!("./samples/sample_1.txt");
-        assert_eq!(solve_part_1(input), 2);
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).to_string(), answer);
+            });
     }
 
     #[test]
     fn case_2() {
-        let input = include_str!("./samples/sample_1.txt");
-        assert_eq!(solve_part_2(input), 4);
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).to_string(), answer);
+            });
     }
 }
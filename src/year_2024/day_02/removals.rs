@@ -0,0 +1,131 @@
+use super::parse_levels;
+
+/// Represents the smallest set of levels which must be removed for a report to be safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Removals {
+    /// Represents the indices of the levels which are kept, in ascending order.
+    pub kept: Vec<usize>,
+    /// Represents the indices of the levels which are removed, in ascending order.
+    pub removed: Vec<usize>,
+}
+
+impl Removals {
+    /// Returns the number of levels which must be removed.
+    pub fn count(&self) -> usize {
+        self.removed.len()
+    }
+
+    /// Find the longest chain of levels where every adjacent pair satisfies `step`.
+    fn longest_chain<F>(levels: &[u32], step: F) -> Vec<usize>
+    where
+        F: Fn(u32, u32) -> bool,
+    {
+        // For each level, track the length of the longest chain ending there
+        // and the index of the previous level in that chain.
+        let chains = levels.iter().enumerate().fold(
+            Vec::new(),
+            |mut chains: Vec<(usize, Option<usize>)>, (idx, &item)| {
+                let best = (0..idx)
+                    // Only extend chains whose last level steps validly onto this one.
+                    .filter(|&prev| step(levels[prev], item))
+                    // Prefer the longest chain, breaking ties by the earliest index.
+                    .max_by_key(|&prev| (chains[prev].0, usize::MAX - prev))
+                    .map_or((1, None), |prev| (chains[prev].0 + 1, Some(prev)));
+
+                chains.push(best);
+                chains
+            },
+        );
+
+        // Start from the end of the longest chain overall.
+        let last = (0..levels.len()).max_by_key(|&idx| (chains[idx].0, usize::MAX - idx));
+
+        // Walk the chain backwards and reverse it into ascending order.
+        let mut kept = std::iter::successors(last, |&idx| chains[idx].1).collect::<Vec<_>>();
+        kept.reverse();
+        kept
+    }
+
+    /// Find the minimum removals which make the given levels safe, with one witness.
+    pub fn find(levels: &[u32]) -> Self {
+        // Find the longest safe chain in either direction independently.
+        let increasing = Self::longest_chain(levels, |x, y| y > x && y - x <= 3);
+        let decreasing = Self::longest_chain(levels, |x, y| x > y && x - y <= 3);
+
+        // Keep whichever direction retains the most levels.
+        let kept = match increasing.len() >= decreasing.len() {
+            true => increasing,
+            false => decreasing,
+        };
+
+        let removed = (0..levels.len())
+            .filter(|idx| kept.binary_search(idx).is_err())
+            .collect();
+
+        Removals { kept, removed }
+    }
+}
+
+/// Find the minimum removals for every report in the input statement.
+pub fn find_all(input: &str) -> Vec<Removals> {
+    input
+        .lines()
+        .map(|line| Removals::find(&parse_levels(line)))
+        .collect()
+}
+
+/// Count how many reports need each number of removals, indexed by that number.
+pub fn histogram(input: &str) -> Vec<u32> {
+    find_all(input)
+        .into_iter()
+        .fold(Vec::new(), |mut histogram, removals| {
+            // Grow the histogram so that the current count has a bucket.
+            if histogram.len() <= removals.count() {
+                histogram.resize(removals.count() + 1, 0);
+            }
+
+            histogram[removals.count()] += 1;
+            histogram
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2024::day_02::{solve_part_1, solve_part_2, Record};
+
+    #[test]
+    fn matches_margin() {
+        let input = include_str!("./samples/sample_1.txt");
+
+        // Every report needs no more removals than any margin it is safe with.
+        input.lines().map(parse_levels).for_each(|levels| {
+            let removals = Removals::find(&levels);
+
+            (0..levels.len()).for_each(|margin| {
+                let safe = Record::try_from(margin, levels.clone()).is_some();
+                assert_eq!(safe, removals.count() <= margin);
+            });
+        });
+    }
+
+    #[test]
+    fn witness() {
+        let removals = Removals::find(&[1, 3, 2, 4, 5]);
+        assert_eq!(removals.kept, vec![0, 1, 3, 4]);
+        assert_eq!(removals.removed, vec![2]);
+
+        let removals = Removals::find(&[1, 2, 7, 8, 9]);
+        assert_eq!(removals.count(), 2);
+    }
+
+    #[test]
+    fn counts() {
+        let input = include_str!("./samples/sample_1.txt");
+        let histogram = histogram(input);
+
+        assert_eq!(histogram[0], solve_part_1(input));
+        assert_eq!(histogram[0] + histogram[1], solve_part_2(input));
+        assert_eq!(histogram.iter().sum::<u32>(), 6);
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt::{self, Display};
+
+use super::vm::{Handler, Registry};
+
+/// Represents the number of characters of context shown on either side of a lint.
+const CONTEXT: usize = 24;
+
+/// Represents the reason why a fragment was rejected, or is suspicious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// Represents whitespace between the name, parentheses or arguments of a call.
+    Whitespace,
+    /// Represents a character which cannot appear in the argument list of a call.
+    InvalidCharacter(char),
+    /// Represents an argument list which is never closed on the same line.
+    Unterminated,
+    /// Represents a call with the wrong number of arguments.
+    WrongArity {
+        /// Represents the number of arguments taken by the instruction.
+        expected: usize,
+        /// Represents the number of arguments given to the call.
+        found: usize,
+    },
+    /// Represents an argument which is empty or does not fit in a `u32`.
+    InvalidNumber,
+    /// Represents an argument with more than the 3 digits allowed by the puzzle.
+    LongNumber,
+    /// Represents a misspelling of `don't`.
+    UnknownInstruction,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Whitespace => write!(f, "whitespace is not allowed in instructions"),
+            Self::InvalidCharacter(c) => write!(f, "unexpected character `{c}` in arguments"),
+            Self::Unterminated => write!(f, "arguments are never closed"),
+            Self::WrongArity { expected, found } => {
+                write!(f, "expected {expected} arguments, found {found}")
+            }
+            Self::InvalidNumber => write!(f, "argument is not a valid number"),
+            Self::LongNumber => write!(f, "argument has more than 3 digits"),
+            Self::UnknownInstruction => write!(f, "unknown instruction, did you mean `don't()`?"),
+        }
+    }
+}
+
+/// Represents a near-miss instruction fragment in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// Represents the byte offset of the fragment in the input.
+    pub offset: usize,
+    /// Represents the length of the fragment in bytes.
+    pub len: usize,
+    /// Represents the instruction the fragment was parsed as.
+    pub instruction: &'static str,
+    /// Represents the reason the fragment was rejected.
+    pub reason: Reason,
+}
+
+/// Represents the arguments of a call following the instruction name.
+struct Call<'a> {
+    /// Represents the number of whitespace bytes between the name and the parenthesis.
+    padding: usize,
+    /// Represents the argument list, up to the first character that cannot appear in it.
+    args: &'a str,
+    /// Represents the character which ended the argument list, if any.
+    end: Option<char>,
+}
+
+impl<'a> Call<'a> {
+    /// Parse the opening parenthesis and argument list following an instruction name.
+    fn parse(rest: &'a str) -> Option<Self> {
+        let trimmed = rest.trim_start_matches([' ', '\t']);
+        let padding = rest.len() - trimmed.len();
+
+        // Assert that the call is opened, otherwise the fragment is not a near miss.
+        let inner = trimmed.strip_prefix("(")?;
+
+        // Take every character which could belong to an argument list.
+        let len = inner
+            .find(|c: char| !(c.is_ascii_digit() || c == ',' || c == ' ' || c == '\t'))
+            .unwrap_or(inner.len());
+
+        Some(Call {
+            padding,
+            args: &inner[..len],
+            end: inner[len..].chars().next(),
+        })
+    }
+
+    /// Returns the length in bytes of the call, including the parenthesis.
+    fn len(&self) -> usize {
+        let end = match self.end {
+            Some(c) if c != '\n' => c.len_utf8(),
+            _ => 0,
+        };
+
+        self.padding + 1 + self.args.len() + end
+    }
+
+    /// Classify the argument list against the arguments taken by the instruction.
+    fn classify(&self, handler: &Handler) -> Option<Reason> {
+        let Handler { arity, digits, .. } = *handler;
+
+        let args = match self.args.is_empty() {
+            true => vec![],
+            false => self.args.split(",").collect(),
+        };
+
+        match self.end {
+            // The argument list is interrupted by some other character.
+            Some(c) if c != ')' && c != '\n' => Some(Reason::InvalidCharacter(c)),
+            // The argument list runs until the end of the line.
+            Some('\n') | None => Some(Reason::Unterminated),
+            // The call is closed, but padded with whitespace.
+            _ if self.padding > 0 || self.args.contains([' ', '\t']) => Some(Reason::Whitespace),
+            // The call is closed, but with the wrong number of arguments.
+            _ if args.len() != arity => Some(Reason::WrongArity {
+                expected: arity,
+                found: args.len(),
+            }),
+            // The call is closed, but some argument is not a number.
+            _ if args.iter().any(|arg| arg.parse::<u32>().is_err()) => Some(Reason::InvalidNumber),
+            // The call is closed, but some argument is longer than the puzzle allows.
+            _ if args
+                .iter()
+                .any(|arg| digits.is_some_and(|digits| arg.len() > digits)) =>
+            {
+                Some(Reason::LongNumber)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Lint {
+    /// Lint a `mul` instruction at the start of the input.
+    fn lint_mul(input: &str, arithmetic: &Registry) -> Option<(&'static str, usize, Reason)> {
+        let call = Call::parse(input.strip_prefix("mul")?)?;
+
+        // Fragments accepted by the `mul` instruction are fine.
+        if arithmetic.prefix(input).is_some() {
+            return None;
+        }
+
+        let reason = call.classify(arithmetic.get("mul")?)?;
+
+        Some(("mul", "mul".len() + call.len(), reason))
+    }
+
+    /// Lint a `do` or `don't` instruction, or a misspelling of one, at the start of the input.
+    fn lint_toggle(input: &str, standard: &Registry) -> Option<(&'static str, usize, Reason)> {
+        // Fragments accepted by the `do` or `don't` instructions are fine.
+        if standard.prefix(input).is_some() {
+            return None;
+        }
+
+        // Take the word starting at the input, such as `do`, `don't` or `do_not`.
+        let len = input
+            .find(|c: char| !(c.is_ascii_alphabetic() || c == '\'' || c == '_'))
+            .unwrap_or(input.len());
+        let (word, rest) = input.split_at(len);
+
+        let call = Call::parse(rest)?;
+
+        let (instruction, reason) = match word {
+            "do" => ("do", call.classify(standard.get("do")?)?),
+            "don't" => ("don't", call.classify(standard.get("don't")?)?),
+            _ if word.len() <= 6 && (word.starts_with("don") || word.starts_with("do_")) => {
+                ("don't", Reason::UnknownInstruction)
+            }
+            _ => return None,
+        };
+
+        Some((instruction, word.len() + call.len(), reason))
+    }
+
+    /// Find every near-miss instruction fragment in the input.
+    pub fn collect(input: &str) -> Vec<Self> {
+        // Build the instructions once, to check every fragment against them.
+        let (arithmetic, standard) = (Registry::arithmetic(), Registry::standard());
+
+        (0..input.len())
+            // Only start linting at character boundaries to avoid slicing through one.
+            .filter(|&offset| input.is_char_boundary(offset))
+            // Lint a fragment starting at every offset, discarding offsets without one.
+            .filter_map(|offset| {
+                let rest = &input[offset..];
+
+                let lint_mul = || Self::lint_mul(rest, &arithmetic);
+                let lint_toggle = || {
+                    rest.starts_with("do")
+                        .then(|| Self::lint_toggle(rest, &standard))?
+                };
+
+                lint_mul()
+                    .or_else(lint_toggle)
+                    .map(|(instruction, len, reason)| Lint {
+                        offset,
+                        len,
+                        instruction,
+                        reason,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the one-based line and column of the fragment in the input.
+    pub fn location(&self, input: &str) -> (usize, usize) {
+        let before = &input[..self.offset];
+
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |idx| &before[idx + 1..])
+            .chars()
+            .count()
+            + 1;
+
+        (line, column)
+    }
+
+    /// Render the lint with its location and a caret underline beneath the fragment.
+    pub fn render(&self, input: &str) -> String {
+        let (line, column) = self.location(input);
+
+        // Find the bounds of the line containing the fragment.
+        let start = input[..self.offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let end = input[self.offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| self.offset + idx);
+
+        // Clip the line to a window of context around the fragment.
+        let from = (start..=self.offset)
+            .rev()
+            .filter(|&idx| input.is_char_boundary(idx))
+            .nth(CONTEXT)
+            .unwrap_or(start);
+        let to = (self.offset + self.len..=end)
+            .filter(|&idx| input.is_char_boundary(idx))
+            .nth(CONTEXT)
+            .unwrap_or(end);
+
+        let prefix = match from > start {
+            true => "...",
+            false => "",
+        };
+        let suffix = match to < end {
+            true => "...",
+            false => "",
+        };
+
+        let gutter = " ".repeat(line.to_string().len());
+        let indent = " ".repeat(prefix.len() + input[from..self.offset].chars().count());
+        let carets = "^".repeat(
+            input[self.offset..self.offset + self.len]
+                .chars()
+                .count()
+                .max(1),
+        );
+
+        format!(
+            "warning: {}: {}\n{gutter}--> {line}:{column}\n{gutter} |\n{line} | {prefix}{}{suffix}\n{gutter} | {indent}{carets}\n",
+            self.instruction,
+            self.reason,
+            &input[from..to],
+        )
+    }
+}
+
+/// Render every near-miss fragment in the input, followed by their number.
+pub fn report(input: &str) -> String {
+    let lints = Lint::collect(input);
+
+    lints
+        .iter()
+        .map(|lint| lint.render(input))
+        .chain([format!("{} near misses found", lints.len())])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reasons(input: &str) -> Vec<(usize, &str, Reason)> {
+        Lint::collect(input)
+            .into_iter()
+            .map(|lint| {
+                (
+                    lint.offset,
+                    &input[lint.offset..lint.offset + lint.len],
+                    lint.reason,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn near_misses() {
+        assert_eq!(
+            reasons("xmul(4*"),
+            vec![(1, "mul(4*", Reason::InvalidCharacter('*'))]
+        );
+        assert_eq!(
+            reasons("mul ( 2 , 4 )"),
+            vec![(0, "mul ( 2 , 4 )", Reason::Whitespace)]
+        );
+        assert_eq!(
+            reasons("mul(1234,5)"),
+            vec![(0, "mul(1234,5)", Reason::LongNumber)]
+        );
+        assert_eq!(
+            reasons("do_not()"),
+            vec![(0, "do_not()", Reason::UnknownInstruction)]
+        );
+        assert_eq!(
+            reasons("don't(1)mul(2)"),
+            vec![
+                (
+                    0,
+                    "don't(1)",
+                    Reason::WrongArity {
+                        expected: 0,
+                        found: 1
+                    }
+                ),
+                (
+                    8,
+                    "mul(2)",
+                    Reason::WrongArity {
+                        expected: 2,
+                        found: 1
+                    }
+                )
+            ]
+        );
+        assert_eq!(reasons("mul(2,4)do()undo()don't()"), vec![]);
+    }
+
+    #[test]
+    fn render() {
+        let input = "mul(2,4)\nx mul(32,64]";
+        let lints = Lint::collect(input);
+
+        assert_eq!(lints[0].location(input), (2, 3));
+        assert_eq!(
+            lints[0].render(input),
+            "warning: mul: unexpected character `]` in arguments\n --> 2:3\n  |\n2 | x mul(32,64]\n  |   ^^^^^^^^^^\n"
+        );
+    }
+}
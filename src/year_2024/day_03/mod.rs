@@ -0,0 +1,157 @@
+use vm::{Machine, Registry};
+
+pub mod lint;
+pub mod vm;
+
+/// Represents a single instruction of the standard `Registry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expr {
+    /// Represents a call to `mul` with both of its arguments.
+    Mul(u32, u32),
+    /// Represents a call to `do`.
+    Enable,
+    /// Represents a call to `don't`.
+    Disable,
+}
+
+impl Expr {
+    /// Convert an instruction parsed by the standard `Registry` into an expression.
+    fn from_call(name: &str, args: &[u32]) -> Option<Self> {
+        match (name, args) {
+            ("mul", &[num1, num2]) => Some(Self::Mul(num1, num2)),
+            ("do", []) => Some(Self::Enable),
+            ("don't", []) => Some(Self::Disable),
+            _ => None,
+        }
+    }
+
+    /// Parse the first expression from the start of the input.
+    pub fn prefix<S>(input: S) -> Option<Self>
+    where
+        S: AsRef<str>,
+    {
+        Registry::standard()
+            .prefix(input.as_ref())
+            .and_then(|(handler, args)| Self::from_call(handler.name, &args))
+    }
+
+    /// Parse every expression in the input, in order.
+    pub fn collect<S>(input: S) -> Vec<Self>
+    where
+        S: AsRef<str>,
+    {
+        Registry::standard()
+            .parse(input.as_ref())
+            .into_iter()
+            .filter_map(|instruction| Self::from_call(instruction.handler.name, &instruction.args))
+            .collect()
+    }
+
+    /// Returns the name and arguments of the instruction represented by the expression.
+    pub fn call(&self) -> (&'static str, Vec<u32>) {
+        match self {
+            Self::Mul(num1, num2) => ("mul", vec![*num1, *num2]),
+            Self::Enable => ("do", vec![]),
+            Self::Disable => ("don't", vec![]),
+        }
+    }
+
+    /// Evaluate the expressions in order on a `Machine` with the standard instructions.
+    pub fn eval<I>(exprs: I) -> u32
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let registry = Registry::standard();
+
+        exprs
+            .into_iter()
+            // Execute each expression as a call to its registered instruction.
+            .fold(Machine::default(), |mut machine, expr| {
+                let (name, args) = expr.call();
+
+                // `Registry::standard` registers every variant, so this is safe.
+                machine.call(registry.get(name).unwrap(), &args);
+
+                machine
+            })
+            .accumulator
+    }
+}
+
+/// Parse the arguments of a `mul` call from the start of the input.
+pub fn prefix_expr(input: &str) -> Option<(u32, u32)> {
+    Registry::arithmetic()
+        .prefix(input)
+        .map(|(_, args)| (args[0], args[1]))
+}
+
+/// Parse a value starting at every offset in the input, discarding offsets without one.
+pub fn extend<T, F>(input: &str, f: F) -> Vec<T>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    (0..input.len())
+        // Only start parsing at character boundaries to avoid slicing through one.
+        .filter(|&idx| input.is_char_boundary(idx))
+        .filter_map(|idx| f(&input[idx..]))
+        .collect()
+}
+
+/// ...
+#[aoc(day3, part1)]
+pub fn solve_part_1(input: &str) -> u32 {
+    // Only `mul` is registered, so every call is evaluated regardless of `do` or `don't`.
+    let program = Registry::arithmetic().parse(input);
+
+    Machine::default().run(program).accumulator
+}
+
+/// ...
+#[aoc(day3, part2)]
+pub fn solve_part_2(input: &str) -> u32 {
+    let program = Registry::standard().parse(input);
+
+    Machine::default().run(program).accumulator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::expected;
+
+    #[test]
+    fn case_1() {
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).to_string(), answer);
+            });
+    }
+
+    #[test]
+    fn case_2() {
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).to_string(), answer);
+            });
+    }
+
+    #[test]
+    fn exprs() {
+        let input = include_str!("./samples/sample_1.txt");
+        let products = extend(input, prefix_expr)
+            .into_iter()
+            .map(|(num1, num2)| num1 * num2);
+        assert_eq!(products.sum::<u32>(), solve_part_1(input));
+
+        let input = include_str!("./samples/sample_2.txt");
+        let exprs = Expr::collect(input);
+        assert_eq!(
+            exprs[..3],
+            [Expr::Mul(2, 4), Expr::Disable, Expr::Mul(5, 5)]
+        );
+        assert_eq!(Expr::prefix("don't()mul(1,2)"), Some(Expr::Disable));
+        assert_eq!(Expr::eval(exprs), solve_part_2(input));
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+/// Represents the function executed by a `Handler` against the machine state.
+pub type Exec = fn(&mut Machine, &[u32]);
+
+/// Represents a single instruction registered with a `Registry`.
+#[derive(Debug, Clone, Copy)]
+pub struct Handler {
+    /// Represents the name of the instruction, as written before its arguments.
+    pub name: &'static str,
+    /// Represents the exact number of arguments taken by the instruction.
+    pub arity: usize,
+    /// Represents the largest number of digits allowed in each argument, if limited.
+    pub digits: Option<usize>,
+    /// Represents the effect of the instruction on the machine state.
+    pub exec: Exec,
+}
+
+impl Handler {
+    /// Parse a call to this instruction from the start of the input.
+    ///
+    /// A call is the instruction name, an opening parenthesis, exactly `arity`
+    /// comma-delimited numbers of at most `digits` digits and a closing parenthesis,
+    /// with no whitespace.
+    pub fn prefix(&self, input: &str) -> Option<Vec<u32>> {
+        input
+            // Strip the name of the instruction and its opening parenthesis.
+            .strip_prefix(self.name)
+            .and_then(|rest| rest.strip_prefix("("))
+            // Take everything up to the closing parenthesis as the argument list.
+            .and_then(|rest| rest.split_once(")"))
+            // Parse every argument, treating an empty list as having no arguments.
+            .and_then(|(args, _)| match args.is_empty() {
+                true => Some(vec![]),
+                false => args.split(",").map(|arg| self.argument(arg)).collect(),
+            })
+            // Assert that the number of arguments matches the arity of the instruction.
+            .filter(|args: &Vec<u32>| args.len() == self.arity)
+    }
+
+    /// Parse a single argument, rejecting it if it has more digits than allowed.
+    fn argument(&self, arg: &str) -> Option<u32> {
+        match self.digits {
+            Some(digits) if arg.len() > digits => None,
+            _ => arg.parse().ok(),
+        }
+    }
+}
+
+/// Represents the set of instructions understood by a `Machine`.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    /// Represents every registered instruction, in order of precedence.
+    handlers: Vec<Handler>,
+}
+
+impl Registry {
+    /// Creates a registry with no instructions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with only the `mul` instruction, taking numbers of up to 3 digits.
+    pub fn arithmetic() -> Self {
+        Self::new()
+            .register("mul", 2, |machine, args| {
+                if machine.enabled {
+                    machine.accumulator += args[0] * args[1];
+                }
+            })
+            .limit("mul", 3)
+    }
+
+    /// Creates a registry with the `mul`, `do` and `don't` instructions.
+    pub fn standard() -> Self {
+        Self::arithmetic()
+            .register("do", 0, |machine, _| machine.enabled = true)
+            .register("don't", 0, |machine, _| machine.enabled = false)
+    }
+
+    /// Registers an instruction, taking precedence below every existing one.
+    pub fn register(mut self, name: &'static str, arity: usize, exec: Exec) -> Self {
+        self.handlers.push(Handler {
+            name,
+            arity,
+            digits: None,
+            exec,
+        });
+        self
+    }
+
+    /// Limits the number of digits in each argument of the instruction with the given name.
+    pub fn limit(mut self, name: &str, digits: usize) -> Self {
+        self.handlers
+            .iter_mut()
+            .filter(|handler| handler.name == name)
+            .for_each(|handler| handler.digits = Some(digits));
+        self
+    }
+
+    /// Returns the instruction registered under the given name.
+    pub fn get(&self, name: &str) -> Option<&Handler> {
+        self.handlers.iter().find(|handler| handler.name == name)
+    }
+
+    /// Parse the first matching instruction from the start of the input.
+    pub fn prefix(&self, input: &str) -> Option<(Handler, Vec<u32>)> {
+        self.handlers
+            .iter()
+            .find_map(|handler| handler.prefix(input).map(|args| (*handler, args)))
+    }
+
+    /// Parse every instruction in the input, along with its byte offset.
+    pub fn parse(&self, input: &str) -> Vec<Instruction> {
+        (0..input.len())
+            // Only start parsing at character boundaries to avoid slicing through one.
+            .filter(|&offset| input.is_char_boundary(offset))
+            // Parse an instruction starting at every offset, discarding offsets without one.
+            .filter_map(|offset| {
+                self.prefix(&input[offset..])
+                    .map(|(handler, args)| Instruction {
+                        offset,
+                        handler,
+                        args,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Represents a parsed call to a registered instruction.
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    /// Represents the byte offset of the instruction in the input.
+    pub offset: usize,
+    /// Represents the instruction being called.
+    pub handler: Handler,
+    /// Represents the arguments of the call.
+    pub args: Vec<u32>,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        f.pad(&format!("{}({})", self.handler.name, args))
+    }
+}
+
+/// Represents the state of the machine evaluating instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Represents whether conditional instructions such as `mul` take effect.
+    pub enabled: bool,
+    /// Represents the running total of the program.
+    pub accumulator: u32,
+    /// Represents the number of executed calls of each instruction.
+    pub calls: BTreeMap<&'static str, usize>,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine {
+            enabled: true,
+            accumulator: 0,
+            calls: BTreeMap::new(),
+        }
+    }
+}
+
+impl Machine {
+    /// Execute a single call to the given instruction.
+    pub fn call(&mut self, handler: &Handler, args: &[u32]) {
+        *self.calls.entry(handler.name).or_insert(0) += 1;
+
+        (handler.exec)(self, args);
+    }
+
+    /// Execute every instruction in order, reporting the state after each one.
+    pub fn run_traced<I, F>(mut self, program: I, mut trace: F) -> Self
+    where
+        I: IntoIterator<Item = Instruction>,
+        F: FnMut(&Instruction, &Machine),
+    {
+        program.into_iter().for_each(|instruction| {
+            self.call(&instruction.handler, &instruction.args);
+            trace(&instruction, &self);
+        });
+
+        self
+    }
+
+    /// Execute every instruction in order.
+    pub fn run<I>(self, program: I) -> Self
+    where
+        I: IntoIterator<Item = Instruction>,
+    {
+        self.run_traced(program, |_, _| ())
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let calls = self
+            .calls
+            .iter()
+            .map(|(name, count)| format!("{name}: {count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(
+            f,
+            "enabled={} accumulator={} calls={{{}}}",
+            self.enabled, self.accumulator, calls
+        )
+    }
+}
+
+/// Render every instruction executed while evaluating the input, with the state after it.
+pub fn trace(registry: &Registry, input: &str) -> String {
+    let mut lines = Vec::new();
+
+    Machine::default().run_traced(registry.parse(input), |instruction, machine| {
+        lines.push(format!(
+            "{:>6}: {:<16} {}",
+            instruction.offset, instruction, machine
+        ))
+    });
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard() {
+        let input = include_str!("./samples/sample_2.txt");
+        let registry = Registry::standard();
+
+        let machine = Machine::default().run(registry.parse(input));
+        assert_eq!(machine.accumulator, 48);
+        assert_eq!(machine.calls.get("mul"), Some(&4));
+        assert_eq!(registry.parse(input)[0].offset, 1);
+    }
+
+    #[test]
+    fn register() {
+        let registry = Registry::standard()
+            .register("add", 2, |machine, args| {
+                machine.accumulator += args[0] + args[1];
+            })
+            .register("toggle", 0, |machine, _| machine.enabled = !machine.enabled);
+
+        let machine = Machine::default().run(registry.parse("add(1,2)toggle()mul(3,4)add(1,)"));
+        assert_eq!(machine.accumulator, 3);
+        assert!(!machine.enabled);
+
+        // Only `mul` is limited to arguments of up to 3 digits.
+        let machine = Machine::default().run(registry.parse("mul(1234,5)add(1234,5)mul(123,5)"));
+        assert_eq!(machine.accumulator, 1239 + 615);
+        assert_eq!(machine.calls.get("mul"), Some(&1));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{iter::successors, num::NonZeroU32};
+
+use crate::common::geometry::{Direction8, Vector};
+use crate::common::grid::Grid;
+use search::WordSearch;
+
+pub mod search;
+pub mod visualize;
+
+/// ...
+pub fn check_linear(grid: Grid<char>) -> Option<NonZeroU32> {
+    // ...
+    (*grid.extract() == 'X').then_some(())?;
+
+    // ...
+    let match_offset = |offset: Vector| -> bool {
+        // ...
+        successors(grid.shift(offset), |grid| grid.shift(offset))
+            // ...
+            .map(|grid| *grid.extract())
+            // ...
+            .take("MAS".len())
+            // ...
+            .eq("MAS".chars())
+    };
+
+    // ...
+    let matches = Direction8::iter()
+        // ...
+        .filter_map(|direction| match_offset(direction.vector()).then_some(1))
+        // ...
+        .sum::<u32>();
+
+    // ...
+    matches.try_into().ok()
+}
+
+/// ...
+pub fn check_cross(grid: Grid<char>) -> bool {
+    // ...
+    let result = (*grid.extract() == 'A').then_some(());
+
+    // ...
+    let result = result.and_then(|_| {
+        // ...
+        let fst = *grid.shift(Direction8::DownRight.vector())?.extract();
+        let snd = *grid.shift(Direction8::UpLeft.vector())?.extract();
+
+        // ...
+        [('M', 'S'), ('S', 'M')].contains(&(fst, snd)).then_some(())
+    });
+
+    // ...
+    let result = result.and_then(|_| {
+        // ...
+        let fst = *grid.shift(Direction8::DownLeft.vector())?.extract();
+        let snd = *grid.shift(Direction8::UpRight.vector())?.extract();
+
+        // ...
+        [('M', 'S'), ('S', 'M')].contains(&(fst, snd)).then_some(())
+    });
+
+    // ...
+    result.is_some()
+}
+
+#[aoc(day4, part1)]
+pub fn solve_part_1(input: &str) -> u32 {
+    // ...
+    let grid = input.parse::<Grid<char>>().unwrap();
+
+    grid.extend(check_linear)
+        .iter_items()
+        .filter_map(|x| *x)
+        .map(|x| x.get())
+        .sum()
+}
+
+#[aoc(day4, part1, AhoCorasick)]
+pub fn solve_part_1_automaton(input: &str) -> u32 {
+    // Read every line of the grid once, rather than walking outwards from every cell.
+    let grid = input.parse::<Grid<char>>().unwrap();
+
+    WordSearch::new(["XMAS"]).find(&grid).len() as u32
+}
+
+#[aoc(day4, part2)]
+pub fn solve_part_2(input: &str) -> u32 {
+    // ...
+    let grid = input.parse::<Grid<char>>().unwrap();
+
+    grid.extend_bits(check_cross).count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::expected;
+
+    #[test]
+    fn case_1() {
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).to_string(), answer);
+                assert_eq!(solve_part_1_automaton(&input).to_string(), answer);
+            });
+    }
+
+    #[test]
+    fn case_2() {
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).to_string(), answer);
+            });
+    }
+}
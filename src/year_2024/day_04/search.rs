@@ -0,0 +1,333 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    iter::successors,
+};
+
+use crate::common::{
+    geometry::{Direction8, Point},
+    grid::Grid,
+};
+
+/// Represents a single state of the automaton, reached after reading some prefix.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Represents the state reached by reading each character after this prefix.
+    next: HashMap<char, usize>,
+    /// Represents the state of the longest proper suffix of this prefix.
+    fail: usize,
+    /// Represents every pattern ending at this state, including through its suffixes.
+    outputs: Vec<usize>,
+}
+
+/// Aho–Corasick automaton finding every occurrence of many patterns in a single pass.
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    /// Represents every state of the automaton, starting with the empty prefix.
+    nodes: Vec<Node>,
+    /// Represents the number of characters in each pattern.
+    lengths: Vec<usize>,
+}
+
+impl Automaton {
+    /// Creates an automaton matching the given patterns, identified by their index.
+    ///
+    /// Empty patterns never match.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut nodes = vec![Node::default()];
+        let mut lengths = Vec::new();
+
+        // Insert every pattern into the trie, marking the state where it ends.
+        patterns.into_iter().enumerate().for_each(|(id, pattern)| {
+            let pattern = pattern.as_ref();
+            lengths.push(pattern.chars().count());
+
+            let end = pattern.chars().fold(0, |state, c| {
+                let fresh = nodes.len();
+                let next = *nodes[state].next.entry(c).or_insert(fresh);
+                if next == fresh {
+                    nodes.push(Node::default());
+                }
+                next
+            });
+
+            if end != 0 {
+                nodes[end].outputs.push(id);
+            }
+        });
+
+        // Compute suffix links in order of depth, so that shorter prefixes are always ready.
+        let mut queue = nodes[0].next.values().copied().collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let edges = nodes[state]
+                .next
+                .iter()
+                .map(|(c, next)| (*c, *next))
+                .collect::<Vec<_>>();
+
+            edges.into_iter().for_each(|(c, next)| {
+                let fail = Self::step(&nodes, nodes[state].fail, c);
+                let fail = if fail == next { 0 } else { fail };
+
+                let inherited = nodes[fail].outputs.clone();
+                nodes[next].fail = fail;
+                nodes[next].outputs.extend(inherited);
+                queue.push_back(next);
+            });
+        }
+
+        Automaton { nodes, lengths }
+    }
+
+    /// Returns the state reached by reading the given character from the given state.
+    fn step(nodes: &[Node], state: usize, c: char) -> usize {
+        successors(Some(state), |state| {
+            (*state != 0).then(|| nodes[*state].fail)
+        })
+        .find_map(|state| nodes[state].next.get(&c).copied())
+        .unwrap_or(0)
+    }
+
+    /// Returns the number of characters in the given pattern.
+    pub fn len(&self, pattern: usize) -> usize {
+        self.lengths[pattern]
+    }
+
+    /// Iterate over every occurrence of every pattern in the given characters.
+    ///
+    /// Each occurrence is given as the index of its first character and its pattern.
+    pub fn find_iter<'a, I>(&'a self, haystack: I) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        I: IntoIterator<Item = char>,
+        I::IntoIter: 'a,
+    {
+        haystack
+            .into_iter()
+            .enumerate()
+            // Advance the automaton through every character, keeping the state after each.
+            .scan(0, |state, (index, c)| {
+                *state = Self::step(&self.nodes, *state, c);
+                Some((index, *state))
+            })
+            // Report every pattern ending at each state, relative to its first character.
+            .flat_map(move |(index, state)| {
+                self.nodes[state]
+                    .outputs
+                    .iter()
+                    .map(move |pattern| (index + 1 - self.lengths[*pattern], *pattern))
+            })
+    }
+}
+
+/// Represents an occurrence of a word in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Match {
+    /// Represents the index of the word in the searcher.
+    pub word: usize,
+    /// Represents the position of the first character of the word.
+    pub start: Point,
+    /// Represents the direction in which the word is read.
+    pub direction: Direction8,
+}
+
+/// Searcher finding many words at once along every line of a grid, in all eight directions.
+///
+/// Every row, column and diagonal is read once in a single direction, and words read in
+/// the opposite direction are found by also searching for their reversal. Palindromes are
+/// reported in both directions, so a word made of a single character is reported eight
+/// times per matching cell, once for each direction, in the same way as `check_linear`.
+#[derive(Debug, Clone)]
+pub struct WordSearch {
+    /// Represents the number of words being searched for.
+    words: usize,
+    /// Represents the automaton matching every word followed by every reversed word.
+    automaton: Automaton,
+}
+
+impl WordSearch {
+    /// Creates a searcher for the given words, identified by their index.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let words = words
+            .into_iter()
+            .map(|word| word.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let reversed = words
+            .iter()
+            .map(|word| word.chars().rev().collect::<String>());
+
+        WordSearch {
+            words: words.len(),
+            automaton: Automaton::new(words.iter().cloned().chain(reversed)),
+        }
+    }
+
+    /// Iterate over every line of the grid, with the direction it is read in.
+    fn lines(grid: &Grid<char>) -> impl Iterator<Item = (Direction8, Vec<Point>)> + '_ {
+        let (rows, cols) = (grid.rows as isize, grid.cols as isize);
+
+        let left = (0..rows).map(|row| Point::new(row, 0));
+        let top = (0..cols).map(|col| Point::new(0, col));
+        let right = (1..rows).map(move |row| Point::new(row, cols - 1));
+
+        // Pair each family of lines with the points on the grid edge where they start.
+        let starts = left
+            .clone()
+            .map(|start| (Direction8::Right, start))
+            .chain(top.clone().map(|start| (Direction8::Down, start)))
+            .chain(left.map(|start| (Direction8::DownRight, start)))
+            .chain(
+                top.clone()
+                    .skip(1)
+                    .map(|start| (Direction8::DownRight, start)),
+            )
+            .chain(top.map(|start| (Direction8::DownLeft, start)))
+            .chain(right.map(|start| (Direction8::DownLeft, start)));
+
+        starts.map(|(direction, start)| {
+            let points = successors(Some(start), |point| Some(*point + direction.vector()))
+                .take_while(|point| grid.get(*point).is_some())
+                .collect();
+
+            (direction, points)
+        })
+    }
+
+    /// Find every occurrence of every word in the grid, in any of the eight directions.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Match> {
+        Self::lines(grid)
+            .flat_map(|(direction, points)| {
+                let chars = points
+                    .iter()
+                    .filter_map(|point| grid.get(*point).copied())
+                    .collect::<Vec<_>>();
+
+                // Reversed words are read backwards, from the end of their occurrence.
+                self.automaton
+                    .find_iter(chars)
+                    .map(|(index, pattern)| match pattern.checked_sub(self.words) {
+                        None => Match {
+                            word: pattern,
+                            start: points[index],
+                            direction,
+                        },
+                        Some(word) => Match {
+                            word,
+                            start: points[index + self.automaton.len(pattern) - 1],
+                            direction: direction.reverse(),
+                        },
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::year_2024::day_04::check_linear;
+
+    /// Returns the number of occurrences of `XMAS` found by the comonad solution.
+    fn count_linear(grid: &Grid<char>) -> usize {
+        grid.extend(check_linear)
+            .iter_items()
+            .flatten()
+            .map(|count| count.get() as usize)
+            .sum()
+    }
+
+    /// Generate a square grid of pseudo-random letters from a fixed seed.
+    fn generate(size: usize, seed: u64) -> Grid<char> {
+        let letters = successors(Some(seed), |state| {
+            Some(state.wrapping_mul(6364136223846793005).wrapping_add(1))
+        })
+        .map(|state| ['X', 'M', 'A', 'S'][(state >> 62) as usize]);
+
+        let letters = letters.take(size * size).collect::<Vec<_>>();
+        Grid::try_from(letters.chunks(size).map(|row| row.to_vec())).unwrap()
+    }
+
+    #[test]
+    fn automaton() {
+        let automaton = Automaton::new(["he", "she", "his", "hers"]);
+        let found = automaton.find_iter("ushers".chars()).collect::<Vec<_>>();
+
+        assert_eq!(found, vec![(1, 1), (2, 0), (2, 3)]);
+    }
+
+    #[test]
+    fn directions() {
+        let grid = Grid::try_from(["XMAS", "AMXS", "SAMX"].map(|line| line.chars())).unwrap();
+        let found = WordSearch::new(["XMAS", "AM"]).find(&grid);
+
+        assert_eq!(found.iter().filter(|found| found.word == 0).count(), 2);
+        assert_eq!(found.iter().filter(|found| found.word == 1).count(), 6);
+        assert!(found.contains(&Match {
+            word: 0,
+            start: Point::new(2, 3),
+            direction: Direction8::Left,
+        }));
+        assert!(found.contains(&Match {
+            word: 1,
+            start: Point::new(1, 0),
+            direction: Direction8::UpRight,
+        }));
+    }
+
+    #[test]
+    fn generated() {
+        let grid = generate(64, 2024);
+
+        assert_eq!(
+            WordSearch::new(["XMAS"]).find(&grid).len(),
+            count_linear(&grid)
+        );
+    }
+
+    #[test]
+    fn single_character() {
+        let grid = Grid::try_from(["XMX", "MXM", "XMX"].map(|line| line.chars())).unwrap();
+        let found = WordSearch::new(["X"]).find(&grid);
+
+        // Each of the five cells lies on four lines, and matches in both directions of each.
+        assert_eq!(found.len(), 5 * 8);
+        assert!(Direction8::iter().all(|direction| found.contains(&Match {
+            word: 0,
+            start: Point::new(1, 1),
+            direction,
+        })));
+    }
+
+    /// Compare both solutions on large generated grids.
+    ///
+    /// Run with `cargo test --release timing -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn timing() {
+        let search = WordSearch::new(["XMAS"]);
+
+        [1000, 2000, 4000].into_iter().for_each(|size| {
+            let grid = generate(size, 2024);
+
+            let start_time = Instant::now();
+            let linear = count_linear(&grid);
+            let linear_time = start_time.elapsed();
+
+            let start_time = Instant::now();
+            let automaton = search.find(&grid).len();
+            let automaton_time = start_time.elapsed();
+
+            println!("{size}x{size}: comonad {linear_time:?}, automaton {automaton_time:?}");
+            assert_eq!(automaton, linear);
+        });
+    }
+}
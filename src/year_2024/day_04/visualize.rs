@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::common::{
+    geometry::{Direction8, Point},
+    grid::{render::render, Colour, Format, Grid, Highlight, ParseError, Style},
+};
+
+use super::{check_cross, search::WordSearch};
+
+/// Highlight every cell which is part of an `XMAS` in any direction.
+pub fn highlight_linear(grid: &Grid<char>) -> Grid<Option<Highlight>> {
+    let matches = WordSearch::new(["XMAS"]).find(grid);
+
+    // Walk along every match, collecting each cell it covers.
+    let cells = matches
+        .iter()
+        .flat_map(|found| {
+            (0.."XMAS".len() as isize).map(|step| found.start + found.direction.vector() * step)
+        })
+        .collect::<HashSet<Point>>();
+
+    grid.extend(|view| cells.contains(&view.cursor).then_some(Highlight::Green))
+}
+
+/// Highlight every cell which is part of an X-MAS, with the centres in a second colour.
+pub fn highlight_cross(grid: &Grid<char>) -> Grid<Option<Highlight>> {
+    let centres = grid.extend_bits(check_cross);
+
+    grid.extend(|view| {
+        // A cell is on a cross if it is a centre, or a diagonal neighbour of one.
+        let arm = [
+            Direction8::UpLeft,
+            Direction8::UpRight,
+            Direction8::DownLeft,
+            Direction8::DownRight,
+        ]
+        .iter()
+        .any(|direction| centres.contains(view.cursor + direction.vector()));
+
+        match (centres.contains(view.cursor), arm) {
+            (true, _) => Some(Highlight::Yellow),
+            (false, true) => Some(Highlight::Cyan),
+            (false, false) => None,
+        }
+    })
+}
+
+/// Render the matches of both parts of the puzzle, one grid after the other.
+pub fn visualize(input: &str, style: Style) -> Result<String, ParseError> {
+    let grid = input.parse::<Grid<char>>()?;
+
+    Ok(format!(
+        "Part 1:\n{}\n\nPart 2:\n{}",
+        render(&grid, &highlight_linear(&grid), style),
+        render(&grid, &highlight_cross(&grid), style)
+    ))
+}
+
+/// Encode an image of the puzzle in the given format, highlighting the matches of a part.
+pub fn export(input: &str, part: u32, format: Format) -> Result<Vec<u8>, ParseError> {
+    let grid = input.parse::<Grid<char>>()?;
+    let highlights = match part {
+        1 => highlight_linear(&grid),
+        _ => highlight_cross(&grid),
+    };
+
+    Ok(format.encode(&highlights, 8, |highlight| {
+        highlight.map_or(Colour::DIM, Colour::from)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain() {
+        let input = "XMAS.\n.M.M.\n..A..\n.S.S.";
+        let rendered = visualize(input, Style::Plain).unwrap();
+
+        assert_eq!(
+            rendered,
+            "Part 1:\nXMAS.\n.M...\n..A..\n...S.\n\nPart 2:\n.....\n.M.M.\n..A..\n.S.S."
+        );
+    }
+}
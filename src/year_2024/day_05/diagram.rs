@@ -0,0 +1,210 @@
+use std::{fmt::Display, hash::Hash};
+
+use crate::common::graph::DiGraph;
+
+use super::{parse_rules, parse_updates};
+
+/// Represents the text format of an exported diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    /// Represents a Graphviz DOT document.
+    Dot,
+    /// Represents a Mermaid flowchart.
+    Mermaid,
+}
+
+/// Represents how a single rule is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EdgeStyle {
+    /// Represents whether the update places the pages of the rule the wrong way round.
+    pub violated: bool,
+    /// Represents whether the rule joins adjacent pages of the sorted update.
+    pub chain: bool,
+}
+
+impl EdgeStyle {
+    /// Returns the colour of the edge, if it is highlighted.
+    fn colour(self) -> Option<&'static str> {
+        match (self.violated, self.chain) {
+            (true, _) => Some("red"),
+            (false, true) => Some("blue"),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Style every rule between the pages of the update, or every rule if there is no update.
+pub fn styles<T>(rules: &DiGraph<T>, update: Option<&[T]>) -> (DiGraph<T>, Vec<EdgeStyle>)
+where
+    T: Hash + Eq + Clone,
+{
+    let Some(update) = update else {
+        let styles = vec![EdgeStyle::default(); rules.edges().count()];
+        return (rules.clone(), styles);
+    };
+
+    let graph = rules.induced(update.iter().cloned());
+    let position = |page: &T| update.iter().position(|item| item == page);
+
+    // The sorted chain is only defined if the rules between the pages have no cycle.
+    let sorted = graph.topological_sort().unwrap_or_default();
+
+    let styles = graph
+        .edges()
+        .map(|(from, to)| EdgeStyle {
+            violated: position(from) > position(to),
+            chain: sorted
+                .windows(2)
+                .any(|pair| (&pair[0], &pair[1]) == (from, to)),
+        })
+        .collect();
+
+    (graph, styles)
+}
+
+/// Render the graph in the given syntax, drawing each edge with its style.
+///
+/// Styles are matched to edges in the order they are iterated by the graph.
+pub fn render<T>(graph: &DiGraph<T>, styles: &[EdgeStyle], syntax: Syntax) -> String
+where
+    T: Hash + Eq + Clone + Display,
+{
+    let edges = graph.edges().zip(styles.iter().copied());
+
+    match syntax {
+        Syntax::Dot => {
+            let nodes = graph.nodes().map(|node| format!("  \"{node}\";"));
+
+            let edges = edges.map(|((from, to), style)| {
+                let attributes = [
+                    style.colour().map(|colour| format!("color={colour}")),
+                    style.chain.then(|| "penwidth=2".to_string()),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+                match attributes.is_empty() {
+                    true => format!("  \"{from}\" -> \"{to}\";"),
+                    false => format!("  \"{from}\" -> \"{to}\" [{}];", attributes.join(", ")),
+                }
+            });
+
+            ["digraph rules {".to_string(), "  rankdir=LR;".to_string()]
+                .into_iter()
+                .chain(nodes)
+                .chain(edges)
+                .chain(["}".to_string()])
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        Syntax::Mermaid => {
+            let nodes = graph.nodes().map(|node| format!("  n{node}[\"{node}\"]"));
+
+            // Mermaid styles links by their index, so they are listed after every link.
+            let (links, link_styles): (Vec<_>, Vec<_>) = edges
+                .enumerate()
+                .map(|(index, ((from, to), style))| {
+                    let arrow = if style.chain { "==>" } else { "-->" };
+                    let link_style = style
+                        .colour()
+                        .map(|colour| format!("  linkStyle {index} stroke:{colour};"));
+
+                    (format!("  n{from} {arrow} n{to}"), link_style)
+                })
+                .unzip();
+
+            ["flowchart LR".to_string()]
+                .into_iter()
+                .chain(nodes)
+                .chain(links)
+                .chain(link_styles.into_iter().flatten())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+}
+
+/// Draw the rules of the puzzle in the given syntax, or only those between the pages of the
+/// update with the given 1-based index.
+pub fn draw(input: &str, update: Option<usize>, syntax: Syntax) -> Result<String, String> {
+    let (rules, updates) = input.split_once("\n\n").ok_or("missing updates")?;
+    let rules = parse_rules(rules).ok_or("invalid rules")?;
+    let updates = parse_updates(updates).map_err(|err| err.to_string())?;
+
+    let update = match update {
+        Some(index) => Some(
+            &**index
+                .checked_sub(1)
+                .and_then(|index| updates.get(index))
+                .ok_or_else(|| format!("no update {index}"))?,
+        ),
+        None => None,
+    };
+
+    let (graph, styles) = styles(&rules, update);
+    Ok(render(&graph, &styles, syntax))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> DiGraph<u32> {
+        DiGraph::from_edges([(1, 2), (2, 3), (1, 3), (4, 1)])
+    }
+
+    #[test]
+    fn dot() {
+        let (graph, edge_styles) = styles(&rules(), Some(&[2, 1, 3]));
+        let dot = render(&graph, &edge_styles, Syntax::Dot);
+
+        assert_eq!(
+            dot,
+            [
+                "digraph rules {",
+                "  rankdir=LR;",
+                "  \"2\";",
+                "  \"1\";",
+                "  \"3\";",
+                "  \"2\" -> \"3\" [color=blue, penwidth=2];",
+                "  \"1\" -> \"2\" [color=red, penwidth=2];",
+                "  \"1\" -> \"3\";",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn mermaid() {
+        let (graph, edge_styles) = styles(&rules(), None);
+        let mermaid = render(&graph, &edge_styles, Syntax::Mermaid);
+
+        assert!(mermaid.starts_with("flowchart LR\n  n1[\"1\"]"));
+        assert!(mermaid.contains("  n4 --> n1"));
+        assert!(!mermaid.contains("linkStyle"));
+
+        let (graph, edge_styles) = styles(&rules(), Some(&[2, 1, 3]));
+        let mermaid = render(&graph, &edge_styles, Syntax::Mermaid);
+
+        assert!(mermaid.contains("  n1 ==> n2"));
+        assert!(mermaid.contains("  linkStyle 0 stroke:blue;"));
+        assert!(mermaid.ends_with("  linkStyle 1 stroke:red;"));
+    }
+
+    #[test]
+    fn missing_updates() {
+        let input = include_str!("./samples/sample_1");
+
+        assert!(draw(input, Some(1), Syntax::Dot).is_ok());
+        assert_eq!(
+            draw(input, Some(0), Syntax::Dot),
+            Err("no update 0".to_string())
+        );
+        assert_eq!(
+            draw(input, Some(7), Syntax::Dot),
+            Err("no update 7".to_string())
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::common::graph::DiGraph;
+use update::{Middle, ParseError, Update, UpdateError};
+
+pub mod diagram;
+pub mod repair;
+pub mod rules;
+pub mod update;
+
+/// ...
+pub fn match_ordered<'a, T>(rules: &DiGraph<T>, items: &'a Update<T>) -> Option<&'a Update<T>>
+where
+    T: Eq + Hash + Clone,
+{
+    items
+        .iter()
+        // Zip each element with the element to its right to form adjacent pairs.
+        .zip(&items[1..])
+        // Assert that all adjacent pairs obey the dependencies using the transitive property.
+        .all(|(lhs, rhs)| rules.contains_edge(lhs, rhs))
+        // Return the update itself upon successful matching.
+        .then_some(items)
+}
+
+/// ...
+///
+/// Returns `Ok(None)` if the update is already ordered, and reports the first page lying on
+/// a cycle if the rules between the pages contradict each other.
+pub fn match_unordered<T>(
+    rules: &DiGraph<T>,
+    items: &Update<T>,
+) -> Result<Option<Update<T>>, UpdateError>
+where
+    T: Eq + Hash + Clone,
+{
+    // Order the items by the rules between them alone.
+    let graph = rules.induced(items.iter().cloned());
+    let Some(new_items) = graph.topological_sort() else {
+        // Pages on a cycle share a component with another page, or have a rule to themselves.
+        let cyclic = graph
+            .components()
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || graph.contains_edge(&component[0], &component[0])
+            })
+            .flatten()
+            .collect::<HashSet<_>>();
+        let index = items
+            .iter()
+            .position(|item| cyclic.contains(item))
+            .unwrap_or(0);
+
+        return Err(UpdateError::Cycle { index });
+    };
+
+    // Reordering distinct pages keeps them distinct, so the update remains valid.
+    (**items != new_items)
+        .then(|| Update::new(new_items))
+        .transpose()
+}
+
+/// ...
+pub fn parse_rules(input: &str) -> Option<DiGraph<u32>> {
+    // Collect all rules into a graph of dependencies.
+    let mut graph = DiGraph::new();
+
+    input
+        .lines()
+        // Break each line into a pair of numbers to represent dependency pairs.
+        .map(|line| line.split_once("|"))
+        // Parse each side of the dependency pair.
+        .map(|pair| pair.and_then(|(lhs, rhs)| Some((lhs.parse().ok()?, rhs.parse().ok()?))))
+        // Insert each dependency pair as an edge in the dependency graph.
+        .try_for_each(|pair| {
+            let (lhs, rhs) = pair?;
+
+            // Either page is added to the graph if it has not been seen before.
+            graph.add_edge(lhs, rhs);
+
+            Some(())
+        })?;
+
+    Some(graph)
+}
+
+/// ...
+pub fn parse_updates(input: &str) -> Result<Vec<Update<u32>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        // Parse each line as an ordered collection of distinct comma-delimited numbers.
+        .map(|(index, line)| {
+            line.parse().map_err(|error| ParseError {
+                line: index + 1,
+                error,
+            })
+        })
+        // Lift the inner result of the parsing computation into the outer scope.
+        .collect()
+}
+
+/// Sum the middle pages of every update accepted by `matches`, in input order.
+///
+/// Reports the line of the first update which cannot be matched, or has no single middle page.
+fn sum_middles<F>(updates: &[Update<u32>], matches: F) -> Result<u32, ParseError>
+where
+    F: Fn(&Update<u32>) -> Result<Option<Update<u32>>, UpdateError>,
+{
+    updates
+        .iter()
+        .zip(1..)
+        // Take the middle page of each accepted update, keeping the line of any error.
+        .filter_map(|(update, line)| {
+            let middle = matches(update).and_then(|update| {
+                update
+                    .map(|update| update.middle(Middle::Strict).copied())
+                    .transpose()
+            });
+
+            middle
+                .map_err(|error| ParseError { line, error })
+                .transpose()
+        })
+        .sum()
+}
+
+#[aoc(day5, part1)]
+pub fn solve_part_1(input: &str) -> Result<u32, ParseError> {
+    // ...
+    let (rules, updates) = input.split_once("\n\n").unwrap();
+
+    // ...
+    let rules = parse_rules(rules).unwrap();
+    let updates = parse_updates(updates)?;
+
+    sum_middles(
+        &updates,
+        |update| Ok(match_ordered(&rules, update).cloned()),
+    )
+}
+
+#[aoc(day5, part2)]
+pub fn solve_part_2(input: &str) -> Result<u32, ParseError> {
+    // ...
+    let (rules, updates) = input.split_once("\n\n").unwrap();
+
+    // ...
+    let rules = parse_rules(rules).unwrap();
+    let updates = parse_updates(updates)?;
+
+    sum_middles(&updates, |update| match_unordered(&rules, update))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::expected;
+
+    #[test]
+    fn case_1() {
+        expected(file!(), 1)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_1(&input).map(|sum| sum.to_string()), Ok(answer));
+            });
+    }
+
+    #[test]
+    fn case_2() {
+        expected(file!(), 2)
+            .into_iter()
+            .for_each(|(input, answer)| {
+                assert_eq!(solve_part_2(&input).map(|sum| sum.to_string()), Ok(answer));
+            });
+    }
+
+    #[test]
+    fn updates() {
+        assert_eq!(
+            parse_updates("75,47,61\n97,13").map(|updates| updates.len()),
+            Ok(2)
+        );
+        assert_eq!(
+            parse_updates("75,47,61\n\n97,13"),
+            Err(ParseError {
+                line: 2,
+                error: update::UpdateError::Empty
+            })
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        // 61 and 29 contradict each other through 13, but 75 is consistent with both.
+        let rules = "61|13\n13|29\n29|61\n75|61\n75|29";
+        let update = "75,29,13,61".parse().unwrap();
+        let rules = parse_rules(rules).unwrap();
+
+        assert_eq!(
+            match_unordered(&rules, &update),
+            Err(UpdateError::Cycle { index: 1 })
+        );
+        assert_eq!(match_unordered(&rules, &"75,61".parse().unwrap()), Ok(None));
+
+        // The cycle is reported by the second part rather than dropping the update.
+        let input = "61|13\n13|29\n29|61\n\n75,47,61\n61,13,29";
+        assert_eq!(
+            solve_part_2(input),
+            Err(ParseError {
+                line: 2,
+                error: UpdateError::Cycle { index: 0 }
+            })
+        );
+        assert_eq!(
+            UpdateError::Cycle { index: 2 }.to_string(),
+            "page 3 is ordered before itself by the rules"
+        );
+    }
+
+    #[test]
+    fn invalid_updates() {
+        let rules = "47|53\n97|13\n97|47\n75|47\n47|13\n53|13";
+
+        // An ordered update of even length has no single middle page.
+        let input = format!("{rules}\n\n97,47,13\n75,47,53,13");
+        let error = ParseError {
+            line: 2,
+            error: update::UpdateError::EvenLength { len: 4 },
+        };
+        assert_eq!(solve_part_1(&input), Err(error));
+        assert_eq!(solve_part_2(&input), Ok(0));
+
+        // Once reordered, the same update is reported by the second part instead.
+        let input = format!("{rules}\n\n97,47,13\n75,53,47,13");
+        assert_eq!(solve_part_1(&input), Ok(47));
+        assert_eq!(solve_part_2(&input), Err(error));
+
+        // Empty updates are reported rather than panicking.
+        let input = format!("{rules}\n\n97,47,13\n");
+        assert_eq!(solve_part_1(&input), Ok(47));
+        let input = format!("{rules}\n\n\n97,47,13");
+        assert_eq!(
+            solve_part_1(&input),
+            Err(ParseError {
+                line: 1,
+                error: update::UpdateError::Empty
+            })
+        );
+    }
+}
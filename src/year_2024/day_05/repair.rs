@@ -0,0 +1,354 @@
+use std::{collections::HashMap, fmt, hash::Hash, mem};
+
+use crate::common::graph::DiGraph;
+
+use super::{
+    rules::{Relation, RuleSet},
+    update::Update,
+};
+
+/// Represents moving a single page to a new position within an update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move<T> {
+    /// Represents the page being moved.
+    pub page: T,
+    /// Represents the index of the page before the move.
+    pub from: usize,
+    /// Represents the index of the page after the move.
+    pub to: usize,
+}
+
+impl<T: fmt::Display> fmt::Display for Move<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.page, self.from, self.to)
+    }
+}
+
+/// Represents the fewest moves which put an update into an order consistent with the rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair<T> {
+    /// Represents the longest subsequence of pages which are left in place.
+    pub kept: Vec<T>,
+    /// Represents every move, to be applied one after another.
+    pub moves: Vec<Move<T>>,
+    /// Represents the update once every move has been applied.
+    pub repaired: Vec<T>,
+}
+
+/// Find a maximum matching from each page to the later pages which must come before it.
+///
+/// Returns the page matched to each page, if any.
+fn matching(conflicts: &[Vec<usize>]) -> Vec<Option<usize>> {
+    let mut matched = vec![None; conflicts.len()];
+
+    // Search for an augmenting path from the given page, using Kuhn's algorithm.
+    fn augment(
+        page: usize,
+        conflicts: &[Vec<usize>],
+        matched: &mut [Option<usize>],
+        seen: &mut [bool],
+    ) -> bool {
+        conflicts[page].iter().any(|&other| {
+            if seen[other] {
+                return false;
+            }
+            seen[other] = true;
+
+            let free = match matched[other] {
+                None => true,
+                Some(owner) => augment(owner, conflicts, matched, seen),
+            };
+            if free {
+                matched[other] = Some(page);
+            }
+
+            free
+        })
+    }
+
+    (0..conflicts.len()).for_each(|page| {
+        augment(
+            page,
+            conflicts,
+            &mut matched,
+            &mut vec![false; conflicts.len()],
+        );
+    });
+
+    // Invert the matching, so that it is indexed by the earlier page.
+    matched
+        .iter()
+        .enumerate()
+        .filter_map(|(other, owner)| owner.map(|owner| (owner, other)))
+        .fold(vec![None; conflicts.len()], |mut pairs, (owner, other)| {
+            pairs[owner] = Some(other);
+            pairs
+        })
+}
+
+/// Find the fewest pages to move so that the update is consistent with the rules.
+///
+/// Returns `None` if the rules contradict each other within the update, in which case no
+/// order is consistent. Pages which are left in place keep their relative order, and every
+/// moved page is placed directly after its predecessor in the repaired update.
+///
+/// Taking an `Update` ensures that every page is distinct, so each one is found by value.
+pub fn repair<T>(rules: &DiGraph<T>, update: &Update<T>) -> Option<Repair<T>>
+where
+    T: Hash + Eq + Clone,
+{
+    let items = &**update;
+    let index = RuleSet::new(rules, items.iter().cloned());
+
+    // Pair every page with each later page which the rules place before it.
+    let conflicts = (0..items.len())
+        .map(|lhs| {
+            (lhs + 1..items.len())
+                .filter_map(|rhs| match index.compare(&items[lhs], &items[rhs]) {
+                    Relation::Greater => Some(Some(rhs)),
+                    Relation::Contradictory => Some(None),
+                    Relation::Less | Relation::Unrelated => None,
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // Conflicts are transitive, so the largest set of pages without a conflict between them
+    // is a maximum antichain. By König's theorem, it is found from a maximum matching by
+    // following alternating paths from every unmatched page.
+    let pairs = matching(&conflicts);
+    let owners = pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(owner, other)| other.map(|other| (other, owner)))
+        .collect::<HashMap<_, _>>();
+
+    let mut left = vec![false; items.len()];
+    let mut right = vec![false; items.len()];
+    let mut stack = (0..items.len())
+        .filter(|page| pairs[*page].is_none())
+        .collect::<Vec<_>>();
+
+    while let Some(page) = stack.pop() {
+        if mem::replace(&mut left[page], true) {
+            continue;
+        }
+
+        conflicts[page].iter().for_each(|&other| {
+            if !mem::replace(&mut right[other], true) {
+                stack.extend(owners.get(&other));
+            }
+        });
+    }
+
+    let kept = (0..items.len())
+        .filter(|page| left[*page] && !right[*page])
+        .collect::<Vec<_>>();
+
+    // Order every page by the rules, chaining the kept pages so that they stay in place.
+    let chain = kept
+        .windows(2)
+        .map(|pair| (items[pair[0]].clone(), items[pair[1]].clone()));
+    let repaired = chain
+        .fold(
+            rules.induced(items.iter().cloned()),
+            |mut graph, (from, to)| {
+                graph.add_edge(from, to);
+                graph
+            },
+        )
+        .topological_sort()?;
+
+    // Move every other page directly after its predecessor in the repaired update.
+    let kept = kept
+        .iter()
+        .map(|page| items[*page].clone())
+        .collect::<Vec<_>>();
+    let mut current = items.to_vec();
+    let moves = repaired
+        .iter()
+        .enumerate()
+        .filter(|(_, page)| !kept.contains(page))
+        .map(|(target, page)| {
+            let from = current.iter().position(|item| item == page).unwrap();
+            current.remove(from);
+
+            let to = match target {
+                0 => 0,
+                _ => {
+                    current
+                        .iter()
+                        .position(|item| *item == repaired[target - 1])
+                        .unwrap()
+                        + 1
+                }
+            };
+            current.insert(to, page.clone());
+
+            Move {
+                page: page.clone(),
+                from,
+                to,
+            }
+        })
+        .collect();
+
+    Some(Repair {
+        kept,
+        moves,
+        repaired,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2024::day_05::{parse_rules, parse_updates, update::UpdateError};
+
+    fn sample() -> (DiGraph<u32>, Vec<Update<u32>>) {
+        let input = include_str!("./samples/sample_1");
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+
+        (parse_rules(rules).unwrap(), parse_updates(updates).unwrap())
+    }
+
+    /// Apply every move in order to the pages, asserting that each one moves the page it names.
+    fn replay<T>(pages: &[T], moves: &[Move<T>]) -> Vec<T>
+    where
+        T: PartialEq + Clone + fmt::Debug,
+    {
+        moves.iter().fold(pages.to_vec(), |mut pages, step| {
+            assert_eq!(pages[step.from], step.page);
+
+            let page = pages.remove(step.from);
+            pages.insert(step.to, page);
+            pages
+        })
+    }
+
+    /// Returns the fewest moves needed to order the pages, by trying every subset to keep.
+    ///
+    /// A subset can stay in place when the rules and its current order together have no cycle.
+    fn fewest_moves(rules: &DiGraph<u32>, pages: &[u32]) -> usize {
+        (0..1u32 << pages.len())
+            .filter(|subset| {
+                let kept = (0..pages.len())
+                    .filter(|index| subset & (1 << index) != 0)
+                    .map(|index| pages[index])
+                    .collect::<Vec<_>>();
+
+                let mut graph = rules.induced(pages.iter().copied());
+                kept.windows(2)
+                    .for_each(|pair| _ = graph.add_edge(pair[0], pair[1]));
+                graph.topological_sort().is_some()
+            })
+            .map(|subset| pages.len() - subset.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    /// Assert that the repair of the update is ordered by the rules, reached by its moves,
+    /// and uses as few moves as possible.
+    fn check(rules: &DiGraph<u32>, update: &Update<u32>) {
+        let fix = repair(rules, update).unwrap();
+
+        assert_eq!(replay(update, &fix.moves), fix.repaired);
+        assert_eq!(fix.moves.len(), fewest_moves(rules, update));
+        assert_eq!(fix.kept.len() + fix.moves.len(), update.len());
+        assert!(fix
+            .repaired
+            .iter()
+            .enumerate()
+            .all(|(index, page)| fix.repaired[index + 1..]
+                .iter()
+                .all(|later| !rules.contains_edge(later, page))));
+    }
+
+    #[test]
+    fn replays() {
+        let (rules, updates) = sample();
+        updates.iter().for_each(|update| check(&rules, update));
+
+        // Reversing a chain of rules keeps only one page in place.
+        let rules = DiGraph::from_edges([(1, 2), (2, 3), (3, 4), (4, 5)]);
+        check(&rules, &Update::new(vec![5, 4, 3, 2, 1]).unwrap());
+        check(&rules, &Update::new(vec![2, 1, 4, 3, 5]).unwrap());
+        check(&rules, &Update::new(vec![3, 9, 1, 5, 2]).unwrap());
+
+        // Shuffle seven pages under a partial order, with a fixed seed.
+        let rules = DiGraph::from_edges(
+            (1..=7u32)
+                .flat_map(|lhs| (lhs + 1..=7).map(move |rhs| (lhs, rhs)))
+                .filter(|(lhs, rhs)| (lhs * rhs) % 3 != 1),
+        );
+        std::iter::successors(Some(2024u64), |state| {
+            Some(state.wrapping_mul(6364136223846793005).wrapping_add(1))
+        })
+        .take(50)
+        .for_each(|state| {
+            let mut pages = (1..=7).collect::<Vec<_>>();
+            (1..pages.len())
+                .rev()
+                .for_each(|index| pages.swap(index, (state >> (index * 4)) as usize % (index + 1)));
+
+            check(&rules, &Update::new(pages).unwrap());
+        });
+
+        // Pages are moved by position, so a repeated page would be ambiguous and is rejected.
+        assert_eq!(
+            replay(
+                &[75, 47, 75],
+                &[Move {
+                    page: 75,
+                    from: 2,
+                    to: 0
+                }]
+            ),
+            vec![75, 75, 47]
+        );
+        assert_eq!(
+            Update::new(vec![75, 47, 75]),
+            Err(UpdateError::Duplicate { index: 2 })
+        );
+    }
+
+    #[test]
+    fn sample_moves() {
+        let (rules, updates) = sample();
+
+        // Valid updates need no moves at all.
+        assert!(repair(&rules, &updates[0]).unwrap().moves.is_empty());
+
+        // 75,97,47,61,53 only needs one of its first two pages moved past the other.
+        let fix = repair(&rules, &updates[3]).unwrap();
+        assert_eq!(fix.repaired, vec![97, 75, 47, 61, 53]);
+        assert_eq!(
+            fix.moves,
+            vec![Move {
+                page: 75,
+                from: 0,
+                to: 1
+            }]
+        );
+        assert_eq!(fix.moves[0].to_string(), "move 75 from 0 to 1");
+
+        // 97,13,75,29,47 keeps three pages in place.
+        let fix = repair(&rules, &updates[5]).unwrap();
+        assert_eq!(fix.repaired, vec![97, 75, 47, 29, 13]);
+        assert_eq!(fix.kept.len(), 3);
+        assert_eq!(fix.moves.len(), 2);
+    }
+
+    #[test]
+    fn contradictions() {
+        let rules = DiGraph::from_edges([(1, 2), (2, 1)]);
+        let update = Update::new(vec![1, 2]).unwrap();
+        assert_eq!(repair(&rules, &update), None);
+
+        // Unrelated pages never need to move.
+        let rules = DiGraph::from_edges([(1, 3)]);
+        let fix = repair(&rules, &Update::new(vec![3, 2, 1]).unwrap()).unwrap();
+        assert_eq!(fix.moves.len(), 1);
+        assert_eq!(fix.kept, vec![2, 1]);
+    }
+}
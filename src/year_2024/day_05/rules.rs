@@ -0,0 +1,117 @@
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+use crate::common::{geometry::Point, graph::DiGraph, grid::BitGrid};
+
+/// Represents how two pages are ordered by the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// Represents a page which must come before the other.
+    Less,
+    /// Represents a page which must come after the other.
+    Greater,
+    /// Represents pages which no chain of rules orders.
+    Unrelated,
+    /// Represents pages which the rules order both ways, through a cycle.
+    Contradictory,
+}
+
+impl Relation {
+    /// Returns the ordering of the pages, if the rules decide one.
+    pub fn ordering(self) -> Option<Ordering> {
+        match self {
+            Self::Less => Some(Ordering::Less),
+            Self::Greater => Some(Ordering::Greater),
+            Self::Unrelated | Self::Contradictory => None,
+        }
+    }
+}
+
+/// Ordering rules restricted to a set of pages, indexed for constant time comparisons.
+///
+/// Only chains of rules passing through the given pages are considered, since rules
+/// about pages outside of an update do not apply to it.
+#[derive(Debug, Clone)]
+pub struct RuleSet<T> {
+    /// Represents the position of every page in the index.
+    pages: HashMap<T, usize>,
+    /// Represents whether a chain of rules leads from the page of each row to that of each column.
+    reachable: BitGrid,
+}
+
+impl<T> RuleSet<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Creates an index of the rules between the given pages.
+    pub fn new<I>(rules: &DiGraph<T>, pages: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let closure = rules.induced(pages).closure();
+
+        let pages = closure
+            .nodes()
+            .enumerate()
+            .map(|(index, page)| (page.clone(), index))
+            .collect::<HashMap<_, _>>();
+
+        // Record every edge of the closure as a bit in a square matrix of pages.
+        let edges = closure
+            .edges()
+            .map(|(from, to)| Point::from((pages[from], pages[to])));
+        let reachable = BitGrid::from_points(pages.len(), pages.len(), edges);
+
+        RuleSet { pages, reachable }
+    }
+
+    /// Returns how the rules order the first page relative to the second.
+    ///
+    /// Pages outside of the set, and a page compared with itself, are `Unrelated`.
+    pub fn compare(&self, lhs: &T, rhs: &T) -> Relation {
+        let Some((lhs, rhs)) = self.pages.get(lhs).zip(self.pages.get(rhs)) else {
+            return Relation::Unrelated;
+        };
+
+        if lhs == rhs {
+            return Relation::Unrelated;
+        }
+
+        let before = self.reachable.contains(Point::from((*lhs, *rhs)));
+        let after = self.reachable.contains(Point::from((*rhs, *lhs)));
+
+        match (before, after) {
+            (true, true) => Relation::Contradictory,
+            (true, false) => Relation::Less,
+            (false, true) => Relation::Greater,
+            (false, false) => Relation::Unrelated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relations() {
+        let rules = DiGraph::from_edges([(1, 2), (2, 3), (3, 1), (4, 5), (5, 6)]);
+
+        // Chains are followed through pages in the set.
+        let pages = RuleSet::new(&rules, [4, 5, 6, 7]);
+        assert_eq!(pages.compare(&4, &6), Relation::Less);
+        assert_eq!(pages.compare(&6, &4), Relation::Greater);
+        assert_eq!(pages.compare(&4, &7), Relation::Unrelated);
+        assert_eq!(pages.compare(&4, &8), Relation::Unrelated);
+
+        // Chains through pages outside of the set do not count.
+        let pages = RuleSet::new(&rules, [4, 6]);
+        assert_eq!(pages.compare(&4, &6), Relation::Unrelated);
+
+        // Cycles make every pair on them contradictory, but only when the cycle is complete.
+        let pages = RuleSet::new(&rules, [1, 2, 3]);
+        assert_eq!(pages.compare(&1, &3), Relation::Contradictory);
+        assert_eq!(pages.compare(&1, &1), Relation::Unrelated);
+        let pages = RuleSet::new(&rules, [1, 3]);
+        assert_eq!(pages.compare(&1, &3).ordering(), Some(Ordering::Greater));
+    }
+}
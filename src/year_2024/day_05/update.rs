@@ -0,0 +1,183 @@
+use std::{collections::HashSet, error::Error, fmt, hash::Hash, ops::Deref, str::FromStr};
+
+/// Represents the reason an update is invalid, or has no single middle page.
+///
+/// Indices are stored 0-based, but displayed as 1-based page positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateError {
+    /// Represents an update without any pages.
+    Empty,
+    /// Represents a page which could not be parsed, at the given 0-based index.
+    InvalidPage { index: usize },
+    /// Represents a page which already appeared earlier, at the given 0-based index.
+    Duplicate { index: usize },
+    /// Represents an update with an even number of pages, which has two middle pages.
+    EvenLength { len: usize },
+    /// Represents a page which the rules between the pages order before itself, at the
+    /// given 0-based index, so that no order of the update satisfies every rule.
+    Cycle { index: usize },
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "update has no pages"),
+            Self::InvalidPage { index } => write!(f, "page {} is not a number", index + 1),
+            Self::Duplicate { index } => write!(f, "page {} appears more than once", index + 1),
+            Self::EvenLength { len } => {
+                write!(
+                    f,
+                    "update has {len} pages, so there is no single middle page"
+                )
+            }
+            Self::Cycle { index } => {
+                write!(
+                    f,
+                    "page {} is ordered before itself by the rules",
+                    index + 1
+                )
+            }
+        }
+    }
+}
+
+impl Error for UpdateError {}
+
+/// Represents an invalid update within the updates section of an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// Represents the 1-based line number of the update within the updates section.
+    pub line: usize,
+    /// Represents the reason the update is invalid.
+    pub error: UpdateError,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Represents which page is picked as the middle of an update.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Middle {
+    /// Represents the lower of the two middle pages of an even-length update.
+    Lower,
+    /// Represents the upper of the two middle pages of an even-length update.
+    Upper,
+    /// Represents an error for even-length updates, which have no single middle page.
+    #[default]
+    Strict,
+}
+
+/// Non-empty sequence of distinct pages, in the order they are printed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Update<T>(Vec<T>);
+
+impl<T> Update<T>
+where
+    T: Hash + Eq,
+{
+    /// Creates an update from its pages, asserting that there is at least one and no duplicates.
+    pub fn new(pages: Vec<T>) -> Result<Self, UpdateError> {
+        if pages.is_empty() {
+            return Err(UpdateError::Empty);
+        }
+
+        // Find the first page which was already seen earlier in the update.
+        let mut seen = HashSet::new();
+        if let Some(index) = pages.iter().position(|page| !seen.insert(page)) {
+            return Err(UpdateError::Duplicate { index });
+        }
+
+        Ok(Update(pages))
+    }
+}
+
+impl<T> Update<T> {
+    /// Returns the middle page of the update, picking one as given for even lengths.
+    pub fn middle(&self, middle: Middle) -> Result<&T, UpdateError> {
+        let len = self.0.len();
+
+        match (middle, len % 2) {
+            (Middle::Strict, 0) => Err(UpdateError::EvenLength { len }),
+            (Middle::Lower, 0) => Ok(&self.0[len / 2 - 1]),
+            _ => Ok(&self.0[len / 2]),
+        }
+    }
+
+    /// Returns the pages of the update.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Update<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for Update<u32> {
+    type Err = UpdateError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if line.is_empty() {
+            return Err(UpdateError::Empty);
+        }
+
+        let pages = line
+            .split(",")
+            .enumerate()
+            // Parse each comma-delimited page, remembering the position of the first failure.
+            .map(|(index, page)| page.parse().map_err(|_| UpdateError::InvalidPage { index }))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Update::new(pages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middles() {
+        let odd = "75,47,61".parse::<Update<u32>>().unwrap();
+        assert_eq!(odd.middle(Middle::Strict), Ok(&47));
+        assert_eq!(odd.middle(Middle::Lower), Ok(&47));
+
+        let even = "75,47,61,53".parse::<Update<u32>>().unwrap();
+        assert_eq!(even.middle(Middle::Lower), Ok(&47));
+        assert_eq!(even.middle(Middle::Upper), Ok(&61));
+        assert_eq!(
+            even.middle(Middle::Strict),
+            Err(UpdateError::EvenLength { len: 4 })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Update<u32>>(), Err(UpdateError::Empty));
+        assert_eq!(
+            "75,,61".parse::<Update<u32>>(),
+            Err(UpdateError::InvalidPage { index: 1 })
+        );
+        assert_eq!(
+            "75,47,75".parse::<Update<u32>>(),
+            Err(UpdateError::Duplicate { index: 2 })
+        );
+        assert_eq!(
+            UpdateError::Duplicate { index: 2 }.to_string(),
+            "page 3 appears more than once"
+        );
+        assert_eq!(
+            UpdateError::InvalidPage { index: 1 }.to_string(),
+            "page 2 is not a number"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::runner::{solution, Solution, Tool, Year};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+
+/// Represents every solution and tool of 2024, ordered by day and part.
+///
+/// Every solver is registered twice, once here and once with `#[aoc]` next to it for
+/// `cargo aoc`, so adding or renaming a solver means editing both. The `new` command adds
+/// both for new days, and the `registered` test fails when the two drift apart.
+pub const YEAR: Year = Year {
+    year: 2024,
+    solutions: &[
+        solution!(1, 1, day_01::solve_part_1),
+        solution!(1, 2, day_01::solve_part_2),
+        solution!(2, 1, day_02::solve_part_1),
+        solution!(2, 2, day_02::solve_part_2),
+        solution!(3, 1, day_03::solve_part_1),
+        solution!(3, 2, day_03::solve_part_2),
+        solution!(4, 1, day_04::solve_part_1),
+        solution!(4, 1, AhoCorasick, day_04::solve_part_1_automaton),
+        solution!(4, 2, day_04::solve_part_2),
+        solution!(5, 1, day_05::solve_part_1),
+        solution!(5, 2, day_05::solve_part_2),
+    ],
+    tools: &[
+        (
+            2,
+            Tool::Analyze(|input, json| Ok(day_02::analysis::report(input, json))),
+        ),
+        (
+            3,
+            Tool::Trace(|input| Ok(day_03::vm::trace(&day_03::vm::Registry::standard(), input))),
+        ),
+        (3, Tool::Lint(|input| Ok(day_03::lint::report(input)))),
+        (
+            4,
+            Tool::Visualize(|input, style| {
+                day_04::visualize::visualize(input, style).map_err(|err| err.to_string())
+            }),
+        ),
+        (
+            4,
+            Tool::Export(|input, part, format| {
+                day_04::visualize::export(input, part, format).map_err(|err| err.to_string())
+            }),
+        ),
+        (
+            5,
+            Tool::Graph(|input, update, mermaid| {
+                let syntax = match mermaid {
+                    true => day_05::diagram::Syntax::Mermaid,
+                    false => day_05::diagram::Syntax::Dot,
+                };
+
+                day_05::diagram::draw(input, update, syntax)
+            }),
+        ),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+    use crate::puzzle::expected;
+
+    /// Returns the day, part and name of every `#[aoc]` attribute in the given source.
+    fn attributes(source: &str) -> Vec<(u32, u32, Option<String>)> {
+        source
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("#[aoc(")?.strip_suffix(")]"))
+            .map(|args| {
+                let args = args.split(',').map(str::trim).collect::<Vec<_>>();
+                let day = args[0].strip_prefix("day").unwrap().parse().unwrap();
+                let part = args[1].strip_prefix("part").unwrap().parse().unwrap();
+
+                (day, part, args.get(2).map(|name| name.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn registered() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/year_2024");
+
+        // Collect every solver given to `cargo aoc`, from every source file of every day.
+        let mut solvers = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_dir())
+            .flat_map(|path| fs::read_dir(path).unwrap())
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .flat_map(|path| attributes(&fs::read_to_string(path).unwrap()))
+            .collect::<Vec<_>>();
+        solvers.sort();
+
+        let mut registered = YEAR
+            .solutions
+            .iter()
+            .map(|solution| {
+                let name = solution.name.map(str::to_string);
+                (solution.day, solution.part, name)
+            })
+            .collect::<Vec<_>>();
+        registered.sort();
+
+        assert_eq!(registered, solvers);
+
+        // Every tool belongs to a day with solutions.
+        assert!(YEAR
+            .tools
+            .iter()
+            .all(|(day, _)| YEAR.solutions.iter().any(|solution| solution.day == *day)));
+    }
+
+    #[test]
+    fn answers() {
+        // Every registered solver must be the one for its part, so it solves the samples.
+        YEAR.solutions.iter().for_each(|solution| {
+            let source = format!("src/year_2024/day_{:02}/mod.rs", solution.day);
+
+            expected(&source, solution.part)
+                .into_iter()
+                .for_each(|(sample, answer)| assert_eq!((solution.solve)(&sample), Ok(answer)));
+        });
+    }
+}